@@ -0,0 +1,896 @@
+use crate::models::{I18nFieldOptions, TableRowDeriveInput, TableRowField};
+use darling::util::IdentString;
+use heck::ToTitleCase;
+use proc_macro2::{Ident, TokenStream};
+use quote::{quote, ToTokens};
+use syn::{__private::TokenStream2, Error, PathSegment, Type, WhereClause};
+
+fn get_default_renderer_for_field_getter(
+    class_prop: &TokenStream,
+    value_prop: &TokenStream,
+    index_prop: &TokenStream,
+    segment: &PathSegment,
+    field: &TableRowField,
+    getter: &TokenStream2,
+) -> TokenStream {
+    match get_inner_type(segment, "FieldGetter") {
+        Ok(type_ident) => get_default_renderer_for_type(
+            class_prop, value_prop, index_prop, type_ident, field, getter,
+        ),
+        Err(err) => err.to_compile_error(),
+    }
+}
+
+fn get_default_render_for_inner_type(
+    class_prop: &TokenStream,
+    value_prop: &TokenStream2,
+    index_prop: &TokenStream,
+    field: &TableRowField,
+    type_ident: &syn::Type,
+) -> TokenStream {
+    let format_props = get_format_props_for_field(field, type_ident);
+    let marker = field.marker.as_ref().map_or_else(
+        || get_default_cell_value_marker(type_ident),
+        |marker| quote! { #marker },
+    );
+
+    quote! {
+        <leptos_struct_table::DefaultTableCellRenderer<_, #type_ident, #marker> options=#format_props #value_prop #class_prop #index_prop row=row />
+    }
+}
+
+// TODO: Code duplication with get_field_getter_inner_type --> could be merged in one function
+fn get_inner_type<'a>(
+    segment: &'a PathSegment,
+    outer_type_name: &str,
+) -> Result<&'a syn::Type, syn::Error> {
+    let error_message = format!("`{outer_type_name}` should have one type argument");
+
+    if let syn::PathArguments::AngleBracketed(arg) = &segment.arguments {
+        if arg.args.len() != 1 {
+            return Err(Error::new_spanned(&segment.ident, &error_message));
+        }
+
+        let arg = arg.args.first().expect("just checked above");
+
+        if let syn::GenericArgument::Type(ty) = arg {
+            Ok(ty)
+        } else {
+            Err(Error::new_spanned(&segment.ident, &error_message))
+        }
+    } else {
+        Err(Error::new_spanned(&segment.ident, &error_message))
+    }
+}
+
+fn get_default_option_renderer(
+    class_prop: &TokenStream,
+    index_prop: &TokenStream,
+    type_ident: &syn::Type,
+    field: &TableRowField,
+    getter: &TokenStream2,
+) -> TokenStream {
+    if let Type::Path(path) = &field.ty {
+        let last_segment = path.path.segments.last().expect("not empty");
+
+        return match get_inner_type(last_segment, "Option") {
+            Ok(inner_type_ident) => {
+                let value_prop = quote! {
+                    value=leptos::prelude::Signal::derive(move || value.get().expect("Just checked above that it's not None"))
+                };
+
+                let none_value = field.none_value.clone().unwrap_or_default();
+
+                let inner_renderer = get_default_render_for_inner_type(
+                    class_prop,
+                    &value_prop,
+                    index_prop,
+                    field,
+                    inner_type_ident,
+                );
+
+                quote! {
+                    {
+                        use leptos::prelude::Read;
+
+                        let value = leptos::prelude::Signal::derive(move || { row.read().#getter });
+
+                        leptos::prelude::view! {
+                            <leptos::control_flow::Show
+                                when={
+                                    move || { value.read().is_some() }
+                                }
+                                fallback=move || {
+                                    type DefaultMarker = ();
+                                    leptos::view! {
+                                        <leptos_struct_table::DefaultTableCellRenderer<_, String, DefaultMarker>
+                                            value=leptos::prelude::Signal::stored(#none_value.to_string())
+                                            options={()}
+                                            #class_prop #index_prop row=row
+                                        />
+                                    }
+                                }
+                            >
+                                #inner_renderer
+                            </leptos::control_flow::Show>
+                        }
+                    }
+                }
+            }
+            Err(err) => err.to_compile_error(),
+        };
+    }
+
+    Error::new_spanned(type_ident, "Invalid Option type").to_compile_error()
+}
+
+fn is_option(ty: &syn::Type) -> bool {
+    if let syn::Type::Path(type_path) = ty {
+        if let Some(segment) = type_path.path.segments.last() {
+            if segment.ident == "Option" {
+                return true;
+            }
+        }
+    }
+    false
+}
+
+fn get_default_renderer_for_type(
+    class_prop: &TokenStream,
+    value_prop: &TokenStream,
+    index_prop: &TokenStream,
+    type_ident: &syn::Type,
+    field: &TableRowField,
+    getter: &TokenStream2,
+) -> TokenStream {
+    if is_option(type_ident) {
+        get_default_option_renderer(class_prop, index_prop, type_ident, field, getter)
+    } else {
+        get_default_render_for_inner_type(class_prop, value_prop, index_prop, field, type_ident)
+    }
+}
+
+fn get_format_props_for_field(field: &TableRowField, ty: &syn::Type) -> TokenStream2 {
+    let values: Vec<_> = field
+        .format
+        .iter()
+        .map(|(ident, value)| {
+            quote! {o.#ident = Some(#value.into());}
+        })
+        .collect();
+    let marker = field.marker.as_ref().map_or_else(
+        || get_default_cell_value_marker(ty),
+        |marker| quote! { #marker },
+    );
+
+    quote! {
+        {
+            type DefaultMarker = ();
+            let mut o = <#ty as ::leptos_struct_table::CellValue<#marker>>::RenderOptions::default();
+            #(#values)*
+            o
+      }
+    }
+}
+
+fn get_default_cell_value_marker(ty: &syn::Type) -> TokenStream2 {
+    match ty {
+        Type::Path(path) => {
+            let name = path
+                .path
+                .segments
+                .last()
+                .expect("not empty")
+                .ident
+                .to_string();
+            match &*name {
+                "&String" => quote! { &String },
+                "i8" => quote! { i8 },
+                "i16" => quote! { i16 },
+                "i32" => quote! { i32 },
+                "i64" => quote! { i64 },
+                "i128" => quote! { i128 },
+                "isize" => quote! { isize },
+                "u8" => quote! { u8 },
+                "u16" => quote! { u16 },
+                "u32" => quote! { u32 },
+                "u64" => quote! { u64 },
+                "u128" => quote! { u128 },
+                "usize" => quote! { usize },
+                "f32" => quote! { f32 },
+                "f64" => quote! { f64 },
+                "bool" => quote! { bool },
+                "char" => quote! { char },
+                "IpAddr" => quote! { IpAddr },
+                "Ipv4Addr" => quote! { Ipv4Addr },
+                "Ipv6Addr" => quote! { Ipv6Addr },
+                "SocketAddr" => quote! { SocketAddr },
+                "SocketAddrV4" => quote! { SocketAddrV4 },
+                "SocketAddrV6" => quote! { SocketAddrV6 },
+                "ToUpperCase" => quote! { ToUpperCase },
+                "ToLowerCase" => quote! { ToLowerCase },
+                "NonZeroI8" => quote! { NonZeroI8 },
+                "NonZeroI16" => quote! { NonZeroI16 },
+                "NonZeroI32" => quote! { NonZeroI32 },
+                "NonZeroI64" => quote! { NonZeroI64 },
+                "NonZeroI128" => quote! { NonZeroI128 },
+                "NonZeroIsize" => quote! { NonZeroIsize },
+                "NonZeroU8" => quote! { NonZeroU8 },
+                "NonZeroU16" => quote! { NonZeroU16 },
+                "NonZeroU32" => quote! { NonZeroU32 },
+                "NonZeroU64" => quote! { NonZeroU64 },
+                "NonZeroU128" => quote! { NonZeroU128 },
+                "NonZeroUsize" => quote! { NonZeroUsize },
+                "NaiveDate" => quote! { NaiveDate },
+                "NaiveDateTime" => quote! { NaiveDateTime },
+                "NaiveTime" => quote! { NaiveTime },
+                "Decimal" => quote! { Decimal },
+                "Time" => quote! { Time },
+                "Date" => quote! { Date },
+                "PrimitiveDateTime" => quote! { PrimitiveDateTime },
+                "OffsetDateTime" => quote! { OffsetDateTime },
+                "Uuid" => quote! { Uuid },
+                _ => quote! { DefaultMarker },
+            }
+        }
+        _ => quote! { DefaultMarker },
+    }
+}
+
+fn get_renderer_for_field(name: &Ident, field: &TableRowField, index: usize) -> TokenStream2 {
+    let getter = get_getter(name, &field.getter, &field.ty);
+
+    let index_prop = quote! {
+        index=#index
+    };
+
+    let class = field.cell_class();
+    let class_prop = quote! { class=class_provider.cell( # class) };
+
+    let value_prop = quote! {
+        value={
+            use leptos::prelude::Read;
+
+            leptos::prelude::Signal::derive(move || row.read().#getter)
+        }
+    };
+
+    if let Some(renderer) = &field.renderer {
+        let ident = renderer.as_ident();
+        quote! {
+            <#ident #value_prop #class_prop #index_prop row=row />
+        }
+    } else if let Type::Path(path) = &field.ty {
+        let segment = path.path.segments.last().expect("not empty");
+        let type_ident = &segment.ident;
+
+        if type_ident == "FieldGetter" {
+            get_default_renderer_for_field_getter(
+                &class_prop,
+                &value_prop,
+                &index_prop,
+                segment,
+                field,
+                &getter,
+            )
+        } else {
+            get_default_renderer_for_type(
+                &class_prop,
+                &value_prop,
+                &index_prop,
+                &field.ty,
+                field,
+                &getter,
+            )
+        }
+    } else {
+        panic!("This is not supported")
+    }
+}
+
+fn get_thead_cell_renderer_for_field(thead_cell_renderer: &Option<IdentString>) -> TokenStream2 {
+    if let Some(renderer) = &thead_cell_renderer {
+        let ident = renderer.as_ident();
+        quote! {#ident}
+    } else {
+        quote! {leptos_struct_table::DefaultTableHeaderCellRenderer}
+    }
+}
+
+fn get_getter(name: &Ident, getter: &Option<IdentString>, ty: &Type) -> TokenStream2 {
+    match getter {
+        Some(getter) => quote! { #getter() },
+        None => {
+            if let Type::Path(path) = &ty {
+                let type_ident = &path.path.segments.last().expect("not empty").ident;
+                if type_ident.to_string().as_str() == "FieldGetter" {
+                    return quote! { #name() };
+                }
+            }
+
+            quote! { #name.clone() }
+        }
+    }
+}
+
+/// The type of the value of the column and the expression to access it from `row` by reference.
+fn get_value_ref(
+    row: &TokenStream2,
+    name: &Ident,
+    field: &TableRowField,
+) -> (syn::Type, TokenStream2) {
+    if let Some(getter) = &field.getter {
+        return (field.ty.clone(), quote! { &#row.#getter() });
+    }
+
+    if let Type::Path(path) = &field.ty {
+        let segment = path.path.segments.last().expect("not empty");
+
+        if segment.ident == "FieldGetter" {
+            return match get_inner_type(segment, "FieldGetter") {
+                Ok(ty) => (ty.clone(), quote! { &#row.#name() }),
+                Err(err) => (field.ty.clone(), err.to_compile_error()),
+            };
+        }
+    }
+
+    (field.ty.clone(), quote! { &#row.#name })
+}
+
+fn get_text_value(name: &Ident, field: &TableRowField) -> (syn::Type, TokenStream2) {
+    get_value_ref(&quote! { self }, name, field)
+}
+
+/// The match arm that compares two rows `a` and `b` by the column of the field in ascending order.
+fn get_cmp_arm(name: &Ident, field: &TableRowField, col_index: usize) -> TokenStream2 {
+    let (_, a) = get_value_ref(&quote! { a }, name, field);
+    let (_, b) = get_value_ref(&quote! { b }, name, field);
+
+    match (&field.sort_by, &field.sort_key) {
+        (Some(_), Some(sort_key)) => {
+            let err =
+                Error::new_spanned(sort_key, "`sort_by` and `sort_key` can't be used together")
+                    .to_compile_error();
+            quote! { #col_index => #err, }
+        }
+        (Some(sort_by), None) => quote! {
+            #col_index => #sort_by(#a, #b),
+        },
+        (None, Some(sort_key)) => quote! {
+            #col_index => #sort_key(a).partial_cmp(&#sort_key(b)).unwrap_or(std::cmp::Ordering::Equal),
+        },
+        (None, None) => quote! {
+            #col_index => (#a).partial_cmp(#b).unwrap_or(std::cmp::Ordering::Equal),
+        },
+    }
+}
+
+fn get_cell_text_arms(
+    name: &Ident,
+    field: &TableRowField,
+    col_index: usize,
+) -> (TokenStream2, TokenStream2) {
+    let (ty, value) = get_text_value(name, field);
+
+    let inner_type = match &ty {
+        Type::Path(path) if is_option(&ty) => {
+            match get_inner_type(path.path.segments.last().expect("not empty"), "Option") {
+                Ok(inner_type) => Some(inner_type.clone()),
+                Err(err) => {
+                    let err = err.to_compile_error();
+                    return (err.clone(), err);
+                }
+            }
+        }
+        _ => None,
+    };
+    let value_type = inner_type.as_ref().unwrap_or(&ty);
+
+    let format_props = get_format_props_for_field(field, value_type);
+    let marker = field.marker.as_ref().map_or_else(
+        || get_default_cell_value_marker(value_type),
+        |marker| quote! { #marker },
+    );
+    let cell_text = quote! { <#value_type as leptos_struct_table::CellText<#marker>> };
+
+    if inner_type.is_some() {
+        let none_value = field.none_value.clone().unwrap_or_default();
+        let none_export_value = if none_value.is_empty() {
+            quote! { leptos_struct_table::ExportValue::Empty }
+        } else {
+            quote! { leptos_struct_table::ExportValue::Text(#none_value.to_string()) }
+        };
+
+        (
+            quote! {
+                #col_index => match #value {
+                    Some(value) => #cell_text::render_text(value, &#format_props),
+                    None => #none_value.to_string(),
+                },
+            },
+            quote! {
+                #col_index => match #value {
+                    Some(value) => #cell_text::export_value(value, &#format_props),
+                    None => #none_export_value,
+                },
+            },
+        )
+    } else {
+        (
+            quote! { #col_index => #cell_text::render_text(#value, &#format_props), },
+            quote! { #col_index => #cell_text::export_value(#value, &#format_props), },
+        )
+    }
+}
+
+fn get_row_text_logic(
+    ident: &TokenStream,
+    generic_params: &TokenStream,
+    where_clause: &Option<WhereClause>,
+    fields: &[&TableRowField],
+) -> TokenStream2 {
+    let mut cell_text_arms = vec![];
+    let mut export_value_arms = vec![];
+
+    for f in fields.iter().filter(|f| !f.skip) {
+        let name = f.ident.as_ref().expect("named field");
+        let (cell_text_arm, export_value_arm) = get_cell_text_arms(name, f, cell_text_arms.len());
+
+        cell_text_arms.push(cell_text_arm);
+        export_value_arms.push(export_value_arm);
+    }
+
+    quote! {
+        impl #generic_params leptos_struct_table::TableRowText for #ident
+        #where_clause
+        {
+            fn cell_text(&self, col_index: usize) -> String {
+                type DefaultMarker = ();
+
+                match col_index {
+                    #(#cell_text_arms)*
+                    _ => unreachable!("Column index {} out of bounds", col_index),
+                }
+            }
+
+            fn export_value(&self, col_index: usize) -> leptos_struct_table::ExportValue {
+                type DefaultMarker = ();
+
+                match col_index {
+                    #(#export_value_arms)*
+                    _ => unreachable!("Column index {} out of bounds", col_index),
+                }
+            }
+        }
+    }
+}
+
+fn get_data_provider_logic(
+    ident: &TokenStream,
+    generic_params: &TokenStream,
+    where_clause: &Option<WhereClause>,
+    sortable: bool,
+    fields: &[&TableRowField],
+) -> TokenStream2 {
+    let mut column_name_display_arms = vec![];
+
+    let mut column_value_cmp_arms = vec![];
+    let mut is_null_arms = vec![];
+
+    let mut col_index = 0_usize;
+
+    for f in fields.iter() {
+        let name = f.ident.as_ref().expect("named field");
+
+        if f.skip {
+            continue;
+        }
+
+        let name_str = name.to_string();
+
+        column_name_display_arms.push(quote! {
+            #col_index => #name_str,
+        });
+
+        if !f.skip_sort {
+            column_value_cmp_arms.push(get_cmp_arm(name, f, col_index));
+
+            if f.nulls.is_some() {
+                let (ty, value) = get_value_ref(&quote! { row }, name, f);
+                if is_option(&ty) {
+                    is_null_arms.push(quote! { #col_index => (#value).is_none(), });
+                }
+            }
+        }
+
+        col_index += 1;
+    }
+
+    let cmp_fn = quote! {
+        |a: &#ident, b: &#ident, col_index: usize| match col_index {
+            #(#column_value_cmp_arms)*
+            // columns with `skip_sort`
+            _ => std::cmp::Ordering::Equal,
+        }
+    };
+
+    assert!(
+        !column_value_cmp_arms.is_empty(),
+        "At least one sortable field is required"
+    );
+
+    let set_sorting_impl = if sortable {
+        quote! {
+            fn set_sorting(&mut self, sorting: &std::collections::VecDeque<(usize, ColumnSort)>) {
+                leptos_struct_table::sort_rows_by_with_nulls(
+                    self,
+                    sorting,
+                    &<#ident as leptos_struct_table::TableRow>::nulls_ordering(),
+                    |row: &#ident, col_index: usize| match col_index {
+                        #(#is_null_arms)*
+                        _ => false,
+                    },
+                    #cmp_fn,
+                );
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    quote! {
+        impl #generic_params TableDataProvider<#ident> for Vec<#ident>
+        #where_clause
+        {
+            async fn get_rows(&self, range: std::ops::Range<usize> ) -> Result<(Vec<#ident>, std::ops::Range<usize>), String> {
+                Ok(leptos_struct_table::get_vec_range_clamped(self, range))
+            }
+
+            async fn row_count(&self) -> Option<usize> {
+                Some(self.len())
+            }
+
+            #set_sorting_impl
+        }
+    }
+}
+
+impl ToTokens for TableRowDeriveInput {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        let TableRowDeriveInput {
+            ref ident,
+            ref data,
+            ref generics,
+            ref thead_cell_renderer,
+            ref classes_provider,
+            sortable,
+            impl_vec_data_provider,
+            impl_row_text,
+            ref row_type,
+            ref i18n,
+        } = *self;
+
+        let i18n_path = i18n
+            .as_ref()
+            .and_then(|i18n| i18n.path.as_ref())
+            .map(ToTokens::to_token_stream)
+            .unwrap_or_else(|| quote!(crate::i18n));
+        let i18n_scope = i18n
+            .as_ref()
+            .and_then(|i18n| i18n.scope.as_ref())
+            .map(ToTokens::to_token_stream);
+
+        let fields = data.as_ref().take_struct().expect("Is not enum").fields;
+
+        let generic_params = &generics.params;
+        let where_clause = &generics.where_clause;
+        let generic_params_wb = if generic_params.is_empty() {
+            quote! {}
+        } else {
+            quote! {<#generic_params>}
+        };
+
+        let ident = row_type.as_ref().map_or(
+            quote! { #ident #generic_params_wb },
+            |row_type| quote! { #row_type },
+        );
+
+        let mut titles = vec![];
+        let mut cells = vec![];
+        let mut col_name_match_arms = vec![];
+        let mut col_title_match_arms = vec![];
+        let mut first_sort_match_arms = vec![];
+        let mut default_sorts = vec![];
+        let mut nulls_orders = vec![];
+        let mut db_column_match_arms = vec![];
+        let mut sort_expr_match_arms = vec![];
+
+        for f in &fields {
+            let name = f.ident.as_ref().expect("named field");
+            let name_str = name.to_string();
+
+            if f.skip {
+                continue;
+            }
+
+            let title = if f.skip_header {
+                quote! { "" }
+            } else if cfg!(feature = "i18n")
+                && !f.i18n.as_ref().is_some_and(I18nFieldOptions::is_skipped)
+            {
+                match f.i18n.as_ref().and_then(|i18n| i18n.key.as_ref()) {
+                    Some(key_path) => {
+                        quote!({ #i18n_path::t!(_i18n, #key_path) })
+                    }
+                    None => quote! { { #i18n_path::t!(_i18n, #name) } },
+                }
+            } else if let Some(ref title) = f.title {
+                quote! { #title }
+            } else {
+                let title = name_str.to_title_case();
+                quote! { #title }
+            };
+
+            let head_class = f.head_class();
+
+            let thead_cell_renderer = get_thead_cell_renderer_for_field(thead_cell_renderer);
+
+            let index = titles.len();
+
+            let on_click_handling = if sortable && !f.skip_sort {
+                quote! {
+                    on_click=move |event| on_head_click.with_value(|on_head_click| on_head_click(event))
+                }
+            } else {
+                quote! { on_click=|_| () }
+            };
+
+            col_name_match_arms.push(quote! {#index => #name_str,});
+
+            let plain_title = f.title.clone().unwrap_or_else(|| name_str.to_title_case());
+            col_title_match_arms.push(quote! {#index => #plain_title,});
+
+            if let Some(sort_first) = f.sort_first {
+                first_sort_match_arms.push(quote! {#index => #sort_first,});
+            }
+
+            if let Some(ref db_column) = f.db_column {
+                db_column_match_arms.push(quote! {#index => #db_column,});
+            }
+            if let Some(ref sort_expr) = f.sort_expr {
+                sort_expr_match_arms.push(quote! {#index => Some(#sort_expr),});
+            }
+
+            if let Some(nulls) = f.nulls {
+                if is_option(&f.ty) {
+                    nulls_orders.push(quote! { .column(#index, #nulls) });
+                } else {
+                    tokens.extend(
+                        Error::new_spanned(&f.ty, "`nulls` only applies to `Option` fields")
+                            .to_compile_error(),
+                    );
+                }
+            }
+
+            match (f.default_sort, f.default_sort_priority) {
+                (Some(default_sort), priority) => {
+                    default_sorts.push((priority.unwrap_or(usize::MAX), index, default_sort));
+                }
+                (None, Some(_)) => {
+                    tokens.extend(
+                        Error::new_spanned(name, "`default_sort_priority` requires `default_sort`")
+                            .to_compile_error(),
+                    );
+                }
+                (None, None) => {}
+            }
+
+            titles.push(quote! {
+                #index => leptos::prelude::IntoAny::into_any(leptos::view! {
+                <#thead_cell_renderer
+                    class=leptos::prelude::Signal::derive(move || class_provider.thead_cell(leptos_struct_table::get_sorting_for_column(#index, sorting), #head_class))
+                    inner_class=class_provider.thead_cell_inner()
+                    index=#index
+                    sort_priority=leptos::prelude::Signal::derive(move || {
+                        use leptos::prelude::Read;
+
+                        let sorting = sorting.read();
+                        if sorting.len() < 2 {
+                            return None;
+                        }
+                        sorting.iter().position(|(index, _)| *index == #index)
+                    })
+                    sort_direction=leptos::prelude::Signal::derive(move || leptos_struct_table::get_sorting_for_column(#index, sorting))
+                    #on_click_handling
+                >
+                    #title
+                </#thead_cell_renderer>
+                }),
+            });
+
+            let cell_renderer = get_renderer_for_field(name, f, cells.len());
+            let cell_index = cells.len();
+            cells.push(quote! {
+                #cell_index => leptos::prelude::IntoAny::into_any(leptos::view! { #cell_renderer }),
+            });
+        }
+
+        let data_provider_logic = if impl_vec_data_provider {
+            get_data_provider_logic(&ident, &generic_params_wb, where_clause, sortable, &fields)
+        } else {
+            quote! {}
+        };
+
+        let row_text_logic = if impl_row_text {
+            get_row_text_logic(&ident, &generic_params_wb, where_clause, &fields)
+        } else {
+            quote! {}
+        };
+
+        let first_sort = if first_sort_match_arms.is_empty() {
+            quote! {}
+        } else {
+            quote! {
+                fn first_sort(col_index: usize) -> leptos_struct_table::ColumnSort {
+                    match col_index {
+                        #(#first_sort_match_arms)*
+                        _ => leptos_struct_table::ColumnSort::Ascending,
+                    }
+                }
+            }
+        };
+
+        let default_sorting = if default_sorts.is_empty() {
+            quote! {}
+        } else {
+            // stable so fields without a priority stay in the order of the struct
+            default_sorts.sort_by_key(|(priority, _, _)| *priority);
+            let default_sorts = default_sorts
+                .iter()
+                .map(|(_, index, default_sort)| quote! { (#index, #default_sort) });
+
+            quote! {
+                fn default_sorting() -> std::collections::VecDeque<(usize, leptos_struct_table::ColumnSort)> {
+                    std::collections::VecDeque::from([#(#default_sorts),*])
+                }
+            }
+        };
+
+        let nulls_ordering = if nulls_orders.is_empty() {
+            quote! {}
+        } else {
+            quote! {
+                fn nulls_ordering() -> leptos_struct_table::NullsOrdering {
+                    leptos_struct_table::NullsOrdering::default() #(#nulls_orders)*
+                }
+            }
+        };
+
+        let db_column = if db_column_match_arms.is_empty() {
+            quote! {}
+        } else {
+            quote! {
+                fn db_column(col_index: usize) -> &'static str {
+                    match col_index {
+                        #(#db_column_match_arms)*
+                        _ => Self::col_name(col_index),
+                    }
+                }
+            }
+        };
+
+        let sort_expr = if sort_expr_match_arms.is_empty() {
+            quote! {}
+        } else {
+            quote! {
+                fn sort_expr(col_index: usize) -> Option<&'static str> {
+                    match col_index {
+                        #(#sort_expr_match_arms)*
+                        _ => None,
+                    }
+                }
+            }
+        };
+
+        let classes_provider_ident = classes_provider
+            .as_ref()
+            .map(|id| quote! { #id })
+            .unwrap_or(quote! { leptos_struct_table::DummyTableClassesProvider });
+
+        let column_count = cells.len();
+
+        let i18n = if cfg!(feature = "i18n") {
+            if let Some(scope) = i18n_scope {
+                quote! {
+                    let _i18n = {
+                        use #i18n_path::use_i18n;
+                        #i18n_path::use_i18n_scoped!(#scope)
+                    };
+                }
+            } else {
+                quote! {
+                    let _i18n = #i18n_path::use_i18n();
+                }
+            }
+        } else {
+            quote! {}
+        };
+
+        tokens.extend(quote! {
+            #data_provider_logic
+
+            #row_text_logic
+
+            impl #generic_params_wb leptos_struct_table::TableRow for #ident
+            #where_clause
+            {
+                type ClassesProvider = #classes_provider_ident;
+
+                const COLUMN_COUNT: usize = #column_count;
+
+                fn render_row(row: leptos::prelude::RwSignal<Self>, index: usize) -> impl leptos::IntoView {
+                    use leptos_struct_table::TableClassesProvider;
+                    type DefaultMarker = ();
+
+                    leptos_struct_table::render_column_window(#column_count, move |col_index| {
+                        let class_provider = Self::ClassesProvider::new();
+
+                        match col_index {
+                            #(#cells)*
+                            _ => unreachable!("Column index {} out of bounds", col_index),
+                        }
+                    })
+                }
+
+                fn render_head_row<F>(
+                    sorting: leptos::prelude::Signal<std::collections::VecDeque<(usize, leptos_struct_table::ColumnSort)>>,
+                    on_head_click: F,
+                ) -> impl leptos::IntoView
+                where
+                    F: Fn(leptos_struct_table::TableHeadEvent) + Clone + 'static,
+                {
+                    use leptos_struct_table::TableClassesProvider;
+
+                    // the header cells are only ever rendered on the thread of the table
+                    let on_head_click = leptos::prelude::StoredValue::new_local(on_head_click);
+
+                    leptos_struct_table::render_column_window(#column_count, move |col_index| {
+                        let class_provider = Self::ClassesProvider::new();
+
+                        #i18n
+
+                        match col_index {
+                            #(#titles)*
+                            _ => unreachable!("Column index {} out of bounds", col_index),
+                        }
+                    })
+                }
+
+                fn col_name(col_index: usize) -> &'static str {
+                    match col_index {
+                        #(#col_name_match_arms)*
+                        _ => unreachable!("Column index {} out of bounds", col_index),
+                    }
+                }
+
+                fn col_title(col_index: usize) -> String {
+                    match col_index {
+                        #(#col_title_match_arms)*
+                        _ => unreachable!("Column index {} out of bounds", col_index),
+                    }
+                    .to_string()
+                }
+
+                #first_sort
+
+                #default_sorting
+
+                #nulls_ordering
+
+                #db_column
+
+                #sort_expr
+            }
+        });
+    }
+}
@@ -0,0 +1,67 @@
+use crate::table_row::title_case;
+use crate::ColumnSort;
+use leptos::prelude::*;
+
+/// Inline style that hides an element visually but keeps it accessible to screen readers.
+pub const VISUALLY_HIDDEN_STYLE: &str = "position: absolute; width: 1px; height: 1px; padding: 0; margin: -1px; overflow: hidden; clip: rect(0, 0, 0, 0); white-space: nowrap; border: 0;";
+
+/// A live region renderer for the `live_region_renderer` prop of [`TableContent`](crate::TableContent).
+/// Renders a visually hidden `<caption>` that announces the [`TableAnnouncement`]s to screen readers.
+/// Please note that this is **NOT** a `#[component]`.
+#[allow(non_snake_case)]
+pub fn DefaultLiveRegionRenderer(announcement: Signal<String>) -> impl IntoView {
+    view! {
+        <caption aria-live="polite" aria-atomic="true" style=VISUALLY_HIDDEN_STYLE>
+            {announcement}
+        </caption>
+    }
+}
+
+/// A change of the table that is announced to screen readers through the live region of
+/// [`TableContent`](crate::TableContent).
+///
+/// The messages can be customized (e.g. translated) with the `format_announcement` prop.
+///
+/// ```
+/// # use leptos_struct_table::*;
+/// let announcement = TableAnnouncement::Sorted {
+///     col_index: 0,
+///     col_name: "published_at",
+///     sort: ColumnSort::Descending,
+/// };
+///
+/// assert_eq!(announcement.default_message(), "Sorted by Published At descending");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TableAnnouncement {
+    /// The table is now sorted by the given column first.
+    Sorted {
+        col_index: usize,
+        col_name: &'static str,
+        sort: ColumnSort,
+    },
+    /// The table is not sorted anymore.
+    SortingCleared,
+    /// The first rows after a (re)load have been loaded. `row_count` is the total number of rows if known.
+    Loaded { row_count: Option<usize> },
+    /// Loading rows failed.
+    LoadingFailed,
+}
+
+impl TableAnnouncement {
+    /// The English message of this announcement.
+    pub fn default_message(&self) -> String {
+        match self {
+            TableAnnouncement::Sorted { col_name, sort, .. } => {
+                format!("Sorted by {} {}", title_case(col_name), sort.as_aria_sort())
+            }
+            TableAnnouncement::SortingCleared => "Sorting removed".to_string(),
+            TableAnnouncement::Loaded { row_count: Some(1) } => "1 row loaded".to_string(),
+            TableAnnouncement::Loaded {
+                row_count: Some(row_count),
+            } => format!("{row_count} rows loaded"),
+            TableAnnouncement::Loaded { row_count: None } => "Rows loaded".to_string(),
+            TableAnnouncement::LoadingFailed => "Loading rows failed".to_string(),
+        }
+    }
+}
@@ -1,3 +1,4 @@
+use crate::ExportValue;
 use leptos::prelude::*;
 
 #[derive(Default, Clone, Copy)]
@@ -16,6 +17,136 @@ pub trait CellValue<M: ?Sized = ()> {
     fn render_value(self, options: Self::RenderOptions) -> impl IntoView;
 }
 
+/// The plain-text counterpart of [`CellValue`] that is used by exporters and the clipboard support.
+/// It receives the same format options as [`CellValue::render_value`].
+pub trait CellText<M: ?Sized = ()>: CellValue<M> {
+    /// Renders the value to plain text.
+    fn render_text(&self, options: &Self::RenderOptions) -> String;
+
+    /// The typed value for exporters that support more than plain text. Defaults to the text.
+    fn export_value(&self, options: &Self::RenderOptions) -> ExportValue {
+        ExportValue::Text(self.render_text(options))
+    }
+}
+
+/// The reverse of [`CellText`]: parses a value from the plain text of a cell, e.g. when pasting
+/// from a spreadsheet. See [`TableRowText::set_cell_text`](crate::TableRowText::set_cell_text).
+///
+/// Like for [`CellValue`] the type parameter `M` is a marker that allows you to implement this trait
+/// for types from other crates.
+///
+/// ```
+/// # use leptos_struct_table::*;
+/// assert_eq!(u32::parse_cell_value(" 42 "), Ok(42));
+/// assert_eq!(Option::<f64>::parse_cell_value(""), Ok(None));
+/// assert!(bool::parse_cell_value("maybe").is_err());
+/// ```
+pub trait ParseCellValue<M: ?Sized = ()>: Sized {
+    /// Parses the value from the text of a cell.
+    fn parse_cell_value(text: &str) -> Result<Self, CellParseError>;
+}
+
+/// Error returned when a cell can't be set from text.
+#[derive(Clone, Debug, PartialEq, Eq, thiserror::Error)]
+pub enum CellParseError {
+    /// The cell can't be edited. Such cells are skipped silently when pasting.
+    #[error("this cell is not editable")]
+    NotEditable,
+
+    /// The text couldn't be parsed. The message is shown in the cell.
+    #[error("{0}")]
+    Invalid(String),
+}
+
+impl ParseCellValue for String {
+    fn parse_cell_value(text: &str) -> Result<Self, CellParseError> {
+        Ok(text.to_string())
+    }
+}
+
+/// Empty text is parsed as `None`.
+impl<T, M> ParseCellValue<M> for Option<T>
+where
+    T: ParseCellValue<M>,
+    M: ?Sized,
+{
+    fn parse_cell_value(text: &str) -> Result<Self, CellParseError> {
+        if text.trim().is_empty() {
+            Ok(None)
+        } else {
+            T::parse_cell_value(text).map(Some)
+        }
+    }
+}
+
+/// Implements [`ParseCellValue`] for types that implement [`std::str::FromStr`].
+/// Surrounding whitespace is ignored.
+macro_rules! parse_cell_value_from_str {
+  ($($child_type:ty),* $(,)?) => {
+    $(
+      impl ParseCellValue for $child_type {
+        fn parse_cell_value(text: &str) -> Result<Self, CellParseError> {
+            text.trim()
+                .parse()
+                .map_err(|err| CellParseError::Invalid(format!("{err}")))
+        }
+      }
+    )*
+  };
+}
+
+#[cfg(any(feature = "chrono", feature = "uuid", feature = "rust_decimal"))]
+pub(crate) use parse_cell_value_from_str;
+
+parse_cell_value_from_str![
+    char,
+    bool,
+    std::net::IpAddr,
+    std::net::SocketAddr,
+    std::net::SocketAddrV4,
+    std::net::SocketAddrV6,
+    std::net::Ipv4Addr,
+    std::net::Ipv6Addr,
+    std::num::NonZeroI8,
+    std::num::NonZeroU8,
+    std::num::NonZeroI16,
+    std::num::NonZeroU16,
+    std::num::NonZeroI32,
+    std::num::NonZeroU32,
+    std::num::NonZeroI64,
+    std::num::NonZeroU64,
+    std::num::NonZeroI128,
+    std::num::NonZeroU128,
+    std::num::NonZeroIsize,
+    std::num::NonZeroUsize,
+    usize,
+    u8,
+    u16,
+    u32,
+    u64,
+    u128,
+    isize,
+    i8,
+    i16,
+    i32,
+    i64,
+    i128,
+    f32,
+    f64,
+];
+
+impl CellText for String {
+    fn render_text(&self, _options: &Self::RenderOptions) -> String {
+        self.clone()
+    }
+}
+
+impl CellText for &'static str {
+    fn render_text(&self, _options: &Self::RenderOptions) -> String {
+        self.to_string()
+    }
+}
+
 impl<V> CellValue<()> for V
 where
     V: IntoView,
@@ -38,6 +169,12 @@ macro_rules! viewable_primitive {
             self.to_string()
         }
       }
+
+      impl CellText<$child_type> for $child_type {
+        fn render_text(&self, _options: &Self::RenderOptions) -> String {
+            self.to_string()
+        }
+      }
     )*
   };
 }
@@ -89,10 +226,75 @@ macro_rules! viewable_number_primitive {
         }
         }
       }
+
+      impl CellText<$child_type> for $child_type {
+        fn render_text(&self, options: &Self::RenderOptions) -> String {
+            if let Some(value) = options.precision.as_ref() {
+                format!("{:.value$}", self)
+            } else {
+                self.to_string()
+            }
+        }
+
+        fn export_value(&self, options: &Self::RenderOptions) -> ExportValue {
+            self.export_number(options.precision)
+        }
+      }
     )*
   };
 }
 
+/// Converts a number to an [`ExportValue`] without losing precision.
+pub(crate) trait ExportNumber {
+    fn export_number(&self, precision: Option<usize>) -> ExportValue;
+}
+
+/// The largest integer up to which all integers can be stored exactly in an `f64` (2^53).
+const MAX_EXACT_INT: u128 = 1 << 53;
+
+/// Integers that don't fit exactly into an `f64` are exported as [`ExportValue::Decimal`].
+macro_rules! export_integer {
+    ($abs:expr; $($ty:ty),* $(,)?) => {
+        $(
+            impl ExportNumber for $ty {
+                #[allow(clippy::unnecessary_cast)]
+                fn export_number(&self, precision: Option<usize>) -> ExportValue {
+                    if $abs(*self) <= MAX_EXACT_INT {
+                        ExportValue::Number {
+                            value: *self as f64,
+                            precision,
+                        }
+                    } else {
+                        ExportValue::Decimal {
+                            value: self.to_string(),
+                            precision,
+                        }
+                    }
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! export_float {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl ExportNumber for $ty {
+                fn export_number(&self, precision: Option<usize>) -> ExportValue {
+                    ExportValue::Number {
+                        value: *self as f64,
+                        precision,
+                    }
+                }
+            }
+        )*
+    };
+}
+
 viewable_number_primitive![
     usize, u8, u16, u32, u64, u128, isize, i8, i16, i32, i64, i128, f32, f64,
 ];
+
+export_integer![|value| value as u128; usize, u8, u16, u32, u64, u128];
+export_integer![|value| (value as i128).unsigned_abs(); isize, i8, i16, i32, i64, i128];
+export_float![f32, f64];
@@ -1,7 +1,7 @@
 //! Support for [::chrono] crate.
 
 use crate::*;
-use ::chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+use ::chrono::{Datelike, NaiveDate, NaiveDateTime, NaiveTime, Timelike};
 use leptos::prelude::*;
 
 #[derive(Clone, Default)]
@@ -13,19 +13,31 @@ pub struct RenderChronoOptions {
 macro_rules! chrono_cell_value_impl {
     (
         $(#[$outer:meta])*
-        $ty:ty
+        $ty:ty,
+        |$value:ident| $export_value:expr
     ) => {
         $(#[$outer])*
         impl CellValue<$ty> for $ty {
             type RenderOptions = RenderChronoOptions;
 
             fn render_value(self, options: Self::RenderOptions) -> impl IntoView {
+                self.render_text(&options)
+            }
+        }
+
+        impl CellText<$ty> for $ty {
+            fn render_text(&self, options: &Self::RenderOptions) -> String {
                 if let Some(value) = options.string.as_ref() {
                     self.format(&value).to_string()
                 } else {
                     self.to_string()
                 }
             }
+
+            fn export_value(&self, _options: &Self::RenderOptions) -> ExportValue {
+                let $value = self;
+                $export_value
+            }
         }
     };
 }
@@ -43,7 +55,12 @@ chrono_cell_value_impl!(
     ///     my_field: NaiveDate
     /// }
     /// ```
-    NaiveDate
+    NaiveDate,
+    |date| ExportValue::Date {
+        year: date.year(),
+        month: date.month() as u8,
+        day: date.day() as u8,
+    }
 );
 
 chrono_cell_value_impl!(
@@ -59,7 +76,16 @@ chrono_cell_value_impl!(
     ///     my_field: NaiveDateTime
     /// }
     /// ```
-    NaiveDateTime
+    NaiveDateTime,
+    |date_time| ExportValue::DateTime {
+        year: date_time.year(),
+        month: date_time.month() as u8,
+        day: date_time.day() as u8,
+        hour: date_time.hour() as u8,
+        minute: date_time.minute() as u8,
+        second: date_time.second() as u8,
+        nanosecond: date_time.nanosecond(),
+    }
 );
 
 chrono_cell_value_impl!(
@@ -75,5 +101,14 @@ chrono_cell_value_impl!(
     ///     my_field: NaiveTime
     /// }
     /// ```
-    NaiveTime
+    NaiveTime,
+    |time| ExportValue::Time {
+        hour: time.hour() as u8,
+        minute: time.minute() as u8,
+        second: time.second() as u8,
+        nanosecond: time.nanosecond(),
+    }
 );
+
+// Parses the ISO 8601 formats that the types' `FromStr` implementations accept.
+parse_cell_value_from_str![NaiveDate, NaiveDateTime, NaiveTime];
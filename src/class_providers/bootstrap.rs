@@ -14,5 +14,38 @@ impl TableClassesProvider for BootstrapClassesPreset {
         format!("{} {}", active, template_classes)
     }
 
+    fn selected_cell(&self, _row_index: usize, _col_index: usize) -> String {
+        "table-active".to_string()
+    }
+
+    fn anchor_cell(&self, _row_index: usize, _col_index: usize) -> String {
+        "border border-primary".to_string()
+    }
+
+    fn cell_error(&self, _row_index: usize, _col_index: usize) -> String {
+        "table-danger".to_string()
+    }
+
+    fn cell_error_message(&self) -> String {
+        "invalid-feedback d-block".to_string()
+    }
+
+    fn pager(&self, template_classes: &str) -> String {
+        format!(
+            "{} {}",
+            "d-flex align-items-center justify-content-between", template_classes
+        )
+    }
+
+    fn pager_button(&self, active: bool, _disabled: bool) -> String {
+        let active = if active { "active" } else { "" };
+
+        format!("btn btn-sm btn-outline-primary {}", active)
+    }
+
+    fn pager_info(&self) -> String {
+        "text-muted".to_string()
+    }
+
     // TODO : skeleton loading
 }
@@ -76,6 +76,53 @@ pub trait TableClassesProvider {
     fn cell(&self, macro_class: &str) -> String {
         macro_class.to_string()
     }
+
+    #[allow(unused_variables)]
+    /// Get the additional classes for body cells that are part of a [`Selection::Cells`](crate::Selection::Cells).
+    fn selected_cell(&self, row_index: usize, col_index: usize) -> String {
+        "selected".to_string()
+    }
+
+    #[allow(unused_variables)]
+    /// Get the additional classes for the cell where the active range of a [`Selection::Cells`](crate::Selection::Cells)
+    /// started. These are added to the classes from [`Self::selected_cell`].
+    fn anchor_cell(&self, row_index: usize, col_index: usize) -> String {
+        "anchor".to_string()
+    }
+
+    #[allow(unused_variables)]
+    /// Get the additional classes for body cells that couldn't be set from pasted text.
+    /// See [`TableClipboard`](crate::TableClipboard).
+    fn cell_error(&self, row_index: usize, col_index: usize) -> String {
+        "invalid".to_string()
+    }
+
+    /// Get the classes for the element inside of a body cell that shows why pasting into it failed.
+    fn cell_error_message(&self) -> String {
+        "cell-error".to_string()
+    }
+
+    /// Get the classes for the root element of the [`Pager`](crate::Pager).
+    /// The `prop_class` parameter contains the classes specified in the `class` prop of the [`Pager`](crate::Pager).
+    fn pager(&self, prop_class: &str) -> String {
+        prop_class.to_string()
+    }
+
+    /// Get the classes for the buttons of the [`Pager`](crate::Pager).
+    /// The `active` parameter indicates whether the button is the one of the current page.
+    /// The `disabled` parameter indicates whether the button can't be clicked.
+    fn pager_button(&self, active: bool, disabled: bool) -> String {
+        match (active, disabled) {
+            (true, _) => "active".to_string(),
+            (false, true) => "disabled".to_string(),
+            (false, false) => "".to_string(),
+        }
+    }
+
+    /// Get the classes for the element of the [`Pager`](crate::Pager) that shows which rows are displayed.
+    fn pager_info(&self) -> String {
+        "".to_string()
+    }
 }
 
 #[derive(Copy, Clone)]
@@ -32,6 +32,8 @@ impl TableClassesProvider for TailwindClassesPreset {
         "flex items-center after:content-[--sort-icon] after:pl-1 after:opacity-40 before:content-[--sort-priority] before:order-last before:pl-0.5 before:font-light before:opacity-40".to_string()
     }
 
+    // `usize::is_multiple_of` requires Rust 1.87
+    #[allow(unknown_lints, clippy::manual_is_multiple_of)]
     fn row(&self, row_index: usize, selected: bool, template_classes: &str) -> String {
         let bg_color = if row_index % 2 == 0 {
             if selected {
@@ -71,4 +73,43 @@ impl TableClassesProvider for TailwindClassesPreset {
     fn cell(&self, template_classes: &str) -> String {
         format!("{} {}", "px-5 py-2", template_classes)
     }
+
+    fn selected_cell(&self, _row_index: usize, _col_index: usize) -> String {
+        "bg-sky-100 dark:bg-sky-900".to_string()
+    }
+
+    fn anchor_cell(&self, _row_index: usize, _col_index: usize) -> String {
+        "outline outline-2 -outline-offset-2 outline-sky-500".to_string()
+    }
+
+    fn cell_error(&self, _row_index: usize, _col_index: usize) -> String {
+        "bg-red-50 dark:bg-red-950".to_string()
+    }
+
+    fn cell_error_message(&self) -> String {
+        "block text-xs text-red-600 dark:text-red-400".to_string()
+    }
+
+    fn pager(&self, template_classes: &str) -> String {
+        format!(
+            "{} {}",
+            "flex items-center justify-between gap-4 py-3 text-sm text-gray-700 dark:text-gray-400",
+            template_classes
+        )
+    }
+
+    fn pager_button(&self, active: bool, disabled: bool) -> String {
+        let state_class = if active {
+            "text-blue-600 bg-blue-50 dark:bg-gray-700 dark:text-white"
+        } else if disabled {
+            "text-gray-300 bg-white cursor-not-allowed dark:bg-gray-800 dark:text-gray-600"
+        } else {
+            "text-gray-500 bg-white hover:bg-gray-100 hover:text-gray-700 dark:bg-gray-800 dark:text-gray-400 dark:hover:bg-gray-700 dark:hover:text-white"
+        };
+
+        format!(
+            "{} {}",
+            "px-3 h-8 -ml-px border border-gray-300 dark:border-gray-700", state_class
+        )
+    }
 }
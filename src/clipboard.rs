@@ -0,0 +1,578 @@
+use crate::{CellParseError, CellPosition, TableRowText};
+use leptos::prelude::*;
+use leptos::reactive::graph::{AnySource, ToAnySource};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::ops::RangeInclusive;
+use std::rc::Rc;
+use wasm_bindgen::{JsCast, JsValue};
+
+/// Copies the selected rows of a table to the clipboard as tab-separated text and as an HTML table
+/// so they can be pasted into spreadsheet applications like Excel or Google Sheets. In the other
+/// direction blocks of cells copied from a spreadsheet can be pasted into the table.
+///
+/// Pass it to the `clipboard` prop of [`TableContent`](crate::TableContent). Then the selected rows are copied
+/// when the user presses Ctrl+C (or Cmd+C) while no text is selected on the page. You can also copy them
+/// programmatically with [`TableClipboard::copy_selection`].
+///
+/// The cells are rendered with [`TableRowText::cell_text`] and only rows that are currently loaded are copied.
+///
+/// When the user pastes while the focus is inside a cell of the table, the pasted cells are written
+/// starting at that cell with [`TableRowText::set_cell_text`]. The changed rows are reported through the
+/// `on_change` prop of [`TableContent`](crate::TableContent) like any other edit. Cells that fail to parse
+/// keep their value and show the error message (see [`use_cell_error`]).
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_struct_table::*;
+/// # use std::collections::HashSet;
+/// #
+/// #[derive(TableRow, Clone)]
+/// #[table(impl_vec_data_provider)]
+/// pub struct Book {
+///     title: String,
+///     author: String,
+/// }
+///
+/// impl TableRowText for Book {
+///     fn cell_text(&self, col_index: usize) -> String {
+///         match col_index {
+///             0 => self.title.clone(),
+///             _ => self.author.clone(),
+///         }
+///     }
+/// }
+///
+/// #[component]
+/// fn BookTable(rows: Vec<Book>) -> impl IntoView {
+///     let selected_indices = RwSignal::new(HashSet::new());
+///     let clipboard = TableClipboard::<Book>::new().include_header(true);
+///
+///     view! {
+///         <button on:click={
+///             let clipboard = clipboard.clone();
+///             move |_| clipboard.copy_selection()
+///         }>"Copy"</button>
+///         <table>
+///             <TableContent
+///                 rows
+///                 scroll_container="html"
+///                 selection=Selection::Multiple(selected_indices)
+///                 clipboard
+///             />
+///         </table>
+///     }
+/// }
+/// ```
+#[derive(Clone)]
+pub struct TableClipboard<Row: Send + Sync + 'static> {
+    titles: Rc<[String]>,
+    cell_text: fn(&Row, usize) -> String,
+    set_cell_text: fn(&mut Row, usize, &str) -> Result<(), CellParseError>,
+    include_header: bool,
+    paste_delimiter: char,
+    cell_errors: CellErrors,
+    pub(crate) source: Rc<RefCell<Option<ClipboardSource<Row>>>>,
+}
+
+/// Gives the [`TableClipboard`] access to the rows of the [`TableContent`](crate::TableContent) it is passed to.
+pub(crate) struct ClipboardSource<Row: Send + Sync + 'static> {
+    /// The loaded rows that are selected, in the order of the table.
+    pub selected_rows: Box<dyn Fn() -> Vec<RwSignal<Row>>>,
+    /// The selected columns if only a range of cells is selected. `None` means all columns.
+    pub selected_cols: Box<dyn Fn() -> Option<RangeInclusive<usize>>>,
+    /// The row at the given index if it is loaded and currently rendered.
+    pub displayed_row: Box<dyn Fn(usize) -> Option<RwSignal<Row>>>,
+    /// The cell that contains the focused element.
+    pub focused_cell: Box<dyn Fn() -> Option<CellPosition>>,
+}
+
+/// The index of the row that is currently rendered. [`TableContent`](crate::TableContent) provides this
+/// as context to every loaded row so cell renderers can read it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RowIndex(pub usize);
+
+/// Error messages of cells that couldn't be set from pasted text, keyed by the row signal and the column index
+/// so an error stays with its row when the rows move.
+///
+/// An error is removed once a valid value is pasted into the cell or the table is reloaded or sorted.
+#[derive(Clone, Copy, Debug)]
+pub struct CellErrors(RwSignal<HashMap<(AnySource, usize), String>>);
+
+impl CellErrors {
+    fn new() -> Self {
+        Self(RwSignal::new(HashMap::new()))
+    }
+
+    /// The error message of the given cell. Tracks changes when used in a reactive context.
+    pub fn get<Row: Send + Sync + 'static>(
+        &self,
+        row: RwSignal<Row>,
+        col_index: usize,
+    ) -> Option<String> {
+        self.0
+            .read()
+            .get(&(row.to_any_source(), col_index))
+            .cloned()
+    }
+
+    /// Removes the error of the given cell, e.g. after the user edited it.
+    pub fn remove<Row: Send + Sync + 'static>(&self, row: RwSignal<Row>, col_index: usize) {
+        self.0.write().remove(&(row.to_any_source(), col_index));
+    }
+
+    /// Removes all errors.
+    pub fn clear(&self) {
+        self.0.write().clear();
+    }
+}
+
+/// Provided by [`TableContent`](crate::TableContent) if a [`TableClipboard`] is given.
+#[derive(Clone)]
+pub(crate) struct CellErrorContext {
+    pub cell_errors: CellErrors,
+    /// [`TableClassesProvider::cell_error`](crate::TableClassesProvider::cell_error)
+    pub error_class: Callback<(usize, usize), String>,
+    /// [`TableClassesProvider::cell_error_message`](crate::TableClassesProvider::cell_error_message)
+    pub message_class: String,
+}
+
+/// Returns the paste error of the cell with the given column index in `row`.
+/// Use this in custom cell renderers to show errors inline like the [`DefaultTableCellRenderer`](crate::DefaultTableCellRenderer) does.
+pub fn use_cell_error<Row: Send + Sync + 'static>(
+    row: RwSignal<Row>,
+    col_index: usize,
+) -> Signal<Option<String>> {
+    match use_context::<CellErrorContext>() {
+        Some(context) => Signal::derive(move || context.cell_errors.get(row, col_index)),
+        None => Signal::stored(None),
+    }
+}
+
+/// Returns the classes from [`TableClassesProvider::cell_error`](crate::TableClassesProvider::cell_error) for the
+/// cell with the given column index in `row`. Empty if the cell has no paste error. Use this in custom cell renderers.
+pub fn use_cell_error_class<Row: Send + Sync + 'static>(
+    row: RwSignal<Row>,
+    col_index: usize,
+) -> Signal<String> {
+    match (use_context::<CellErrorContext>(), use_context::<RowIndex>()) {
+        (Some(context), Some(RowIndex(row_index))) => Signal::derive(move || {
+            if context.cell_errors.get(row, col_index).is_some() {
+                context.error_class.run((row_index, col_index))
+            } else {
+                String::new()
+            }
+        }),
+        _ => Signal::stored(String::new()),
+    }
+}
+
+/// Returns the classes from [`TableClassesProvider::cell_error_message`](crate::TableClassesProvider::cell_error_message)
+/// for the element that shows the error message of a cell. Use this in custom cell renderers.
+pub fn use_cell_error_message_class() -> String {
+    use_context::<CellErrorContext>()
+        .map(|context| context.message_class)
+        .unwrap_or_default()
+}
+
+impl<Row: TableRowText + Send + Sync + 'static> Default for TableClipboard<Row> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Row: Send + Sync + 'static> TableClipboard<Row> {
+    /// Creates a new clipboard handle. The column titles are read here with [`TableRow::col_title`](crate::TableRow::col_title)
+    /// so call this inside your component.
+    pub fn new() -> Self
+    where
+        Row: TableRowText,
+    {
+        Self {
+            titles: (0..Row::column_count()).map(Row::col_title).collect(),
+            cell_text: Row::cell_text,
+            set_cell_text: Row::set_cell_text,
+            include_header: false,
+            paste_delimiter: '\t',
+            cell_errors: CellErrors::new(),
+            source: Rc::new(RefCell::new(None)),
+        }
+    }
+
+    /// Put the column titles as the first row. Defaults to `false`.
+    pub fn include_header(mut self, include_header: bool) -> Self {
+        self.include_header = include_header;
+        self
+    }
+
+    /// The delimiter between the cells of pasted text. Defaults to `'\t'` which is what spreadsheet
+    /// applications put on the clipboard. Use `','` to paste CSV.
+    pub fn paste_delimiter(mut self, paste_delimiter: char) -> Self {
+        self.paste_delimiter = paste_delimiter;
+        self
+    }
+
+    /// The errors of cells that couldn't be set from pasted text.
+    pub fn cell_errors(&self) -> CellErrors {
+        self.cell_errors
+    }
+
+    /// Returns the titles and the cells of the selected columns and rows.
+    fn selected_cells(&self) -> (Vec<String>, Vec<Vec<String>>) {
+        let (selected_rows, selected_cols) = match &*self.source.borrow() {
+            Some(source) => ((source.selected_rows)(), (source.selected_cols)()),
+            None => return (vec![], vec![]),
+        };
+
+        let cols = selected_cols
+            .unwrap_or(0..=self.titles.len().saturating_sub(1))
+            .filter(|col_index| *col_index < self.titles.len())
+            .collect::<Vec<_>>();
+
+        let titles = cols
+            .iter()
+            .map(|col_index| self.titles[*col_index].clone())
+            .collect();
+
+        let rows = selected_rows
+            .into_iter()
+            .filter_map(|row| {
+                row.try_with_untracked(|row| {
+                    cols.iter()
+                        .map(|col_index| (self.cell_text)(row, *col_index))
+                        .collect()
+                })
+            })
+            .collect();
+
+        (titles, rows)
+    }
+
+    /// Returns the selected rows as tab-separated text with one line per row.
+    /// Cells that contain tabs, line breaks or quotes are put in quotes.
+    /// With [`Selection::Cells`](crate::Selection::Cells) only the cells of the active range are included.
+    /// Returns an empty string if no loaded row is selected.
+    pub fn selection_as_tsv(&self) -> String {
+        let (titles, rows) = self.selected_cells();
+        if rows.is_empty() {
+            return String::new();
+        }
+
+        let mut tsv = String::new();
+
+        if self.include_header {
+            push_tsv_line(&mut tsv, &titles);
+        }
+        for row in &rows {
+            push_tsv_line(&mut tsv, row);
+        }
+
+        tsv
+    }
+
+    /// Returns the selected rows as an HTML `<table>` fragment. The column titles are put in a `<thead>`
+    /// if [`TableClipboard::include_header`] is enabled.
+    /// Returns an empty string if no loaded row is selected.
+    pub fn selection_as_html(&self) -> String {
+        let (titles, rows) = self.selected_cells();
+        if rows.is_empty() {
+            return String::new();
+        }
+
+        let mut html = String::from("<table>");
+
+        if self.include_header {
+            html.push_str("<thead>");
+            push_html_row(&mut html, "th", &titles);
+            html.push_str("</thead>");
+        }
+
+        html.push_str("<tbody>");
+        for row in &rows {
+            push_html_row(&mut html, "td", row);
+        }
+        html.push_str("</tbody></table>");
+
+        html
+    }
+
+    /// Writes the selected rows to the clipboard as `text/plain` (tab-separated) and `text/html`.
+    /// Does nothing if no loaded row is selected.
+    ///
+    /// Browsers only allow this in reaction to a user interaction like a click.
+    pub fn copy_selection(&self) {
+        let tsv = self.selection_as_tsv();
+        if tsv.is_empty() {
+            return;
+        }
+        let html = self.selection_as_html();
+
+        let clipboard = window().navigator().clipboard();
+
+        match clipboard_item(&tsv, &html) {
+            Ok(item) => {
+                let _ = clipboard.write(&js_sys::Array::of1(&item));
+            }
+            Err(_) => {
+                let _ = clipboard.write_text(&tsv);
+            }
+        }
+    }
+
+    /// Handles the `copy` event of the document. Returns `true` if the selected rows were copied.
+    pub(crate) fn on_copy(&self, event: &web_sys::ClipboardEvent) -> bool {
+        // don't interfere with copying text that the user has selected
+        let text_selected = window()
+            .get_selection()
+            .ok()
+            .flatten()
+            .is_some_and(|selection| !selection.is_collapsed());
+        if text_selected {
+            return false;
+        }
+
+        let Some(data) = event.clipboard_data() else {
+            return false;
+        };
+
+        let tsv = self.selection_as_tsv();
+        if tsv.is_empty() {
+            return false;
+        }
+
+        data.set_data("text/plain", &tsv).is_ok()
+            && data
+                .set_data("text/html", &self.selection_as_html())
+                .is_ok()
+    }
+
+    /// Pastes the given text into the table so that its first cell lands in the cell at `row_index`
+    /// and `col_index`. The text is split into rows and cells with [`parse_clipboard_text`].
+    ///
+    /// Cells outside the table, in rows that aren't loaded or currently rendered and cells that aren't
+    /// editable are skipped. Returns the number of cells that were set.
+    pub fn paste(&self, text: &str, row_index: usize, col_index: usize) -> usize {
+        let source = self.source.borrow();
+        let Some(source) = &*source else {
+            return 0;
+        };
+
+        let column_count = self.titles.len();
+        let mut pasted_count = 0;
+        let mut errors = vec![];
+
+        for (i, cells) in parse_clipboard_text(text, self.paste_delimiter)
+            .into_iter()
+            .enumerate()
+        {
+            let row_index = row_index + i;
+            let Some(row) = (source.displayed_row)(row_index) else {
+                continue;
+            };
+            let row_key = row.to_any_source();
+
+            // only notify (and thereby call `on_change`) if at least one cell was set
+            row.maybe_update(|row| {
+                let mut changed = false;
+
+                for (j, text) in cells.iter().enumerate() {
+                    let col_index = col_index + j;
+                    if col_index >= column_count {
+                        break;
+                    }
+
+                    match (self.set_cell_text)(row, col_index, text) {
+                        Ok(()) => {
+                            changed = true;
+                            pasted_count += 1;
+                            errors.push(((row_key.clone(), col_index), None));
+                        }
+                        Err(CellParseError::NotEditable) => {}
+                        Err(err) => {
+                            errors.push(((row_key.clone(), col_index), Some(err.to_string())));
+                        }
+                    }
+                }
+
+                changed
+            });
+        }
+
+        if !errors.is_empty() {
+            self.cell_errors.0.update(|cell_errors| {
+                for (cell, error) in errors {
+                    match error {
+                        Some(error) => cell_errors.insert(cell, error),
+                        None => cell_errors.remove(&cell),
+                    };
+                }
+            });
+        }
+
+        pasted_count
+    }
+
+    /// Handles the `paste` event of the document. Returns `true` if the text was pasted into the table.
+    pub(crate) fn on_paste(&self, event: &web_sys::ClipboardEvent) -> bool {
+        let focused_cell = match &*self.source.borrow() {
+            Some(source) => (source.focused_cell)(),
+            None => None,
+        };
+        let Some(cell) = focused_cell else {
+            return false;
+        };
+
+        let Some(text) = event
+            .clipboard_data()
+            .and_then(|data| data.get_data("text/plain").ok())
+        else {
+            return false;
+        };
+
+        // a single value pasted into an input is left to the browser
+        let single_cell = !text
+            .trim_end_matches(['\r', '\n'])
+            .contains(['\n', self.paste_delimiter]);
+        if single_cell && is_editing(&document().active_element()) {
+            return false;
+        }
+
+        self.paste(&text, cell.row_index, cell.col_index);
+
+        true
+    }
+}
+
+pub(crate) fn is_editing(element: &Option<web_sys::Element>) -> bool {
+    element.as_ref().is_some_and(|element| {
+        matches!(element.tag_name().as_str(), "INPUT" | "TEXTAREA" | "SELECT")
+            || element
+                .dyn_ref::<web_sys::HtmlElement>()
+                .is_some_and(|element| element.is_content_editable())
+    })
+}
+
+/// Splits text from the clipboard into rows and cells.
+///
+/// Rows are separated by line breaks and cells by `delimiter`. Cells can be put in double quotes to contain
+/// delimiters and line breaks, in which case double quotes inside are escaped by doubling them.
+/// A trailing line break is ignored as spreadsheet applications add one.
+///
+/// ```
+/// # use leptos_struct_table::*;
+/// assert_eq!(
+///     parse_clipboard_text("a\tb\r\n\"multi\nline\"\t\"say \"\"hi\"\"\"\n", '\t'),
+///     vec![vec!["a", "b"], vec!["multi\nline", "say \"hi\""]],
+/// );
+/// assert_eq!(parse_clipboard_text("1,2", ','), vec![vec!["1", "2"]]);
+/// ```
+pub fn parse_clipboard_text(text: &str, delimiter: char) -> Vec<Vec<String>> {
+    let mut rows = vec![];
+    let mut row = vec![];
+    let mut cell = String::new();
+    let mut in_quotes = false;
+    let mut at_cell_start = true;
+
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        if in_quotes {
+            if c == '"' {
+                if chars.peek() == Some(&'"') {
+                    chars.next();
+                    cell.push('"');
+                } else {
+                    in_quotes = false;
+                }
+            } else {
+                cell.push(c);
+            }
+            continue;
+        }
+
+        match c {
+            '"' if at_cell_start => {
+                in_quotes = true;
+                at_cell_start = false;
+            }
+            c if c == delimiter => {
+                row.push(std::mem::take(&mut cell));
+                at_cell_start = true;
+            }
+            '\r' | '\n' => {
+                if c == '\r' && chars.peek() == Some(&'\n') {
+                    chars.next();
+                }
+                row.push(std::mem::take(&mut cell));
+                rows.push(std::mem::take(&mut row));
+                at_cell_start = true;
+            }
+            c => {
+                cell.push(c);
+                at_cell_start = false;
+            }
+        }
+    }
+
+    if !at_cell_start || !row.is_empty() {
+        row.push(cell);
+        rows.push(row);
+    }
+
+    rows
+}
+
+fn clipboard_item(tsv: &str, html: &str) -> Result<web_sys::ClipboardItem, JsValue> {
+    let items = js_sys::Object::new();
+
+    for (mime_type, content) in [("text/plain", tsv), ("text/html", html)] {
+        let options = web_sys::BlobPropertyBag::new();
+        options.set_type(mime_type);
+
+        let blob = web_sys::Blob::new_with_str_sequence_and_options(
+            &js_sys::Array::of1(&JsValue::from_str(content)),
+            &options,
+        )?;
+        js_sys::Reflect::set(&items, &JsValue::from_str(mime_type), &blob)?;
+    }
+
+    web_sys::ClipboardItem::new_with_record_from_str_to_blob_promise(&items)
+}
+
+fn push_tsv_line(tsv: &mut String, cells: &[String]) {
+    for (i, cell) in cells.iter().enumerate() {
+        if i > 0 {
+            tsv.push('\t');
+        }
+
+        if cell.contains(['\t', '\n', '\r', '"']) {
+            tsv.push('"');
+            tsv.push_str(&cell.replace('"', "\"\""));
+            tsv.push('"');
+        } else {
+            tsv.push_str(cell);
+        }
+    }
+
+    tsv.push('\n');
+}
+
+fn push_html_row(html: &mut String, cell_tag: &str, cells: &[String]) {
+    html.push_str("<tr>");
+
+    for cell in cells {
+        html.push_str(&format!("<{cell_tag}>"));
+        for c in cell.chars() {
+            match c {
+                '&' => html.push_str("&amp;"),
+                '<' => html.push_str("&lt;"),
+                '>' => html.push_str("&gt;"),
+                '"' => html.push_str("&quot;"),
+                '\n' => html.push_str("<br>"),
+                c => html.push(c),
+            }
+        }
+        html.push_str(&format!("</{cell_tag}>"));
+    }
+
+    html.push_str("</tr>");
+}
@@ -0,0 +1,214 @@
+use leptos::prelude::*;
+use std::ops::Range;
+use std::sync::Arc;
+
+/// The widths of the columns used by [`ColumnVirtualization`] to decide which columns are visible.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ColumnWidths {
+    /// The widths of all columns in pixels are known in advance. Missing widths are taken from the last one.
+    Fixed(Vec<f64>),
+    /// The widths are measured from the rendered cells. Columns that haven't been rendered yet
+    /// are assumed to have the given width in pixels.
+    Measured { estimated_width: f64 },
+}
+
+/// Opt-in horizontal virtualization for tables with a lot of columns. Pass it to the
+/// `column_virtualization` prop of [`TableContent`](crate::TableContent).
+///
+/// Only the columns that intersect the horizontal viewport of the scroll container plus `overscan` columns on
+/// each side are rendered. The other columns are replaced by one spacer cell before and one after the visible
+/// columns. This works together with the vertical virtualization.
+///
+/// The rows have to render their cells with [`render_column_window`] for this to have any effect.
+/// Rows derived with `#[derive(TableRow)]` and [`DynamicRow`](crate::DynamicRow) do that.
+///
+/// ```
+/// # use leptos_struct_table::*;
+/// let column_virtualization = ColumnVirtualization::measured(120.0).overscan(4);
+/// let column_virtualization = ColumnVirtualization::fixed(vec![200.0, 80.0, 80.0]);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct ColumnVirtualization {
+    pub(crate) widths: ColumnWidths,
+    pub(crate) overscan: usize,
+}
+
+impl ColumnVirtualization {
+    /// Virtualization with the given column widths in pixels.
+    pub fn fixed(widths: Vec<f64>) -> Self {
+        Self {
+            widths: ColumnWidths::Fixed(widths),
+            overscan: 2,
+        }
+    }
+
+    /// Virtualization with measured column widths. See [`ColumnWidths::Measured`].
+    pub fn measured(estimated_width: f64) -> Self {
+        Self {
+            widths: ColumnWidths::Measured { estimated_width },
+            overscan: 2,
+        }
+    }
+
+    /// The number of columns that are rendered on each side in addition to the visible ones. Defaults to `2`.
+    pub fn overscan(mut self, overscan: usize) -> Self {
+        self.overscan = overscan;
+        self
+    }
+}
+
+/// The columns that are currently rendered with horizontal virtualization together with the widths
+/// of the spacer cells that replace the columns before and after them.
+///
+/// Provided as context by [`TableContent`](crate::TableContent) if [`ColumnVirtualization`] is enabled.
+#[derive(Clone, Copy, Debug)]
+pub struct ColumnWindow {
+    pub range: Signal<Range<usize>>,
+    pub width_before: Signal<f64>,
+    pub width_after: Signal<f64>,
+}
+
+/// Returns the current [`ColumnWindow`] if horizontal virtualization is enabled.
+pub fn use_column_window() -> Option<ColumnWindow> {
+    use_context::<ColumnWindow>()
+}
+
+/// Returns the widths of all `column_count` columns for the given [`ColumnWidths`].
+pub(crate) fn initial_column_widths(widths: &ColumnWidths, column_count: usize) -> Vec<f64> {
+    match widths {
+        ColumnWidths::Fixed(widths) => {
+            let last_width = widths.last().copied().unwrap_or_default();
+
+            (0..column_count)
+                .map(|col_index| widths.get(col_index).copied().unwrap_or(last_width))
+                .collect()
+        }
+        ColumnWidths::Measured { estimated_width } => vec![*estimated_width; column_count],
+    }
+}
+
+/// Computes the range of columns that intersect the viewport from `x` to `x + viewport_width`
+/// extended by `overscan` columns on each side. Also returns the widths of the columns before and after that range.
+///
+/// ```
+/// # use leptos_struct_table::*;
+/// let widths = [100.0; 10];
+///
+/// assert_eq!(
+///     compute_column_window(&widths, 250.0, 300.0, 1),
+///     (1..7, 100.0, 300.0),
+/// );
+/// ```
+pub fn compute_column_window(
+    widths: &[f64],
+    x: f64,
+    viewport_width: f64,
+    overscan: usize,
+) -> (Range<usize>, f64, f64) {
+    let mut start = widths.len();
+    let mut end = widths.len();
+    let mut offset = 0.0;
+
+    for (col_index, width) in widths.iter().enumerate() {
+        if start == widths.len() && offset + width > x {
+            start = col_index;
+        }
+        if offset >= x + viewport_width {
+            end = col_index;
+            break;
+        }
+        offset += width;
+    }
+
+    let start = start.saturating_sub(overscan);
+    let end = (end + overscan).min(widths.len()).max(start);
+
+    let width_before = widths[..start].iter().sum();
+    let width_after = widths[end..].iter().sum();
+
+    (start..end, width_before, width_after)
+}
+
+/// Renders the cells of a row with horizontal virtualization. Use this in [`TableRow`](crate::TableRow)
+/// implementations to support [`ColumnVirtualization`].
+///
+/// `render_cell` is called with the index of every column in the current [`ColumnWindow`]. Spacer cells are
+/// rendered for the columns before and after the window. If horizontal virtualization is disabled, all
+/// `column_count` cells are rendered.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_struct_table::*;
+/// #[derive(TableRow, Clone)]
+/// struct Wide {
+///     c0: u8, c1: u8, c2: u8, c3: u8, c4: u8, c5: u8, c6: u8, c7: u8,
+///     c8: u8, c9: u8, c10: u8, c11: u8, c12: u8, c13: u8, c14: u8, c15: u8,
+/// }
+///
+/// let row = Wide {
+///     c0: 0, c1: 1, c2: 2, c3: 3, c4: 4, c5: 5, c6: 6, c7: 7,
+///     c8: 8, c9: 9, c10: 10, c11: 11, c12: 12, c13: 13, c14: 14, c15: 15,
+/// };
+///
+/// let (head_html, html) = Owner::new().with(|| {
+///     provide_context(ColumnWindow {
+///         range: Signal::stored(6..9),
+///         width_before: Signal::stored(600.0),
+///         width_after: Signal::stored(700.0),
+///     });
+///
+///     (
+///         Wide::render_head_row(Signal::stored(Default::default()), |_| ()).to_html(),
+///         Wide::render_row(RwSignal::new(row), 0).to_html(),
+///     )
+/// });
+///
+/// assert_eq!(head_html.matches("<th").count(), 3);
+/// assert!(head_html.contains("C6") && !head_html.contains("C9"));
+///
+/// // two spacer cells and the three cells of the window
+/// assert_eq!(html.matches("<td").count(), 5);
+/// assert!(html.contains(">6<") && html.contains(">8<"));
+/// assert!(!html.contains(">5<") && !html.contains(">9<"));
+/// ```
+pub fn render_column_window<F, V>(column_count: usize, render_cell: F) -> AnyView
+where
+    F: Fn(usize) -> V + Send + Sync + 'static,
+    V: IntoView + 'static,
+{
+    match use_column_window() {
+        Some(ColumnWindow {
+            range,
+            width_before,
+            width_after,
+        }) => view! {
+            <ColumnSpacer width=width_before />
+            <For
+                each=move || range.get()
+                key=|col_index| *col_index
+                children={
+                    let render_cell = Arc::new(render_cell);
+                    move |col_index| render_cell(col_index)
+                }
+            />
+            <ColumnSpacer width=width_after />
+        }
+        .into_any(),
+        None => (0..column_count).map(render_cell).collect_view().into_any(),
+    }
+}
+
+/// A cell that takes up the width of the columns that are not rendered.
+#[component]
+fn ColumnSpacer(width: Signal<f64>) -> impl IntoView {
+    view! {
+        <td
+            role="presentation"
+            aria-hidden="true"
+            data-column-spacer=""
+            style="padding: 0; border: 0;"
+            style:min-width=move || format!("{}px", width.get())
+            style:width=move || format!("{}px", width.get())
+        ></td>
+    }
+}
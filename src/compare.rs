@@ -0,0 +1,176 @@
+use std::cmp::Ordering;
+use std::iter::Peekable;
+use std::str::Chars;
+use wasm_bindgen::JsValue;
+
+/// Compares strings in natural order, i.e. runs of digits are compared by their numeric value so
+/// `"item2"` comes before `"item10"`. Everything else is compared character by character.
+///
+/// ```
+/// # use leptos_struct_table::cmp_natural;
+/// # use std::cmp::Ordering;
+/// assert_eq!(cmp_natural("item2", "item10"), Ordering::Less);
+/// assert_eq!(cmp_natural("item10", "item10"), Ordering::Equal);
+/// assert_eq!(cmp_natural("b1", "a20"), Ordering::Greater);
+///
+/// let mut items = vec!["item10", "item1", "item2"];
+/// items.sort_by(|a, b| cmp_natural(a, b));
+/// assert_eq!(items, vec!["item1", "item2", "item10"]);
+/// ```
+pub fn cmp_natural(a: &str, b: &str) -> Ordering {
+    cmp_natural_by(a, b, |a, b| a.cmp(&b))
+}
+
+/// Compares strings without regard to upper and lower case. Strings that only differ in case are equal.
+///
+/// ```
+/// # use leptos_struct_table::cmp_case_insensitive;
+/// # use std::cmp::Ordering;
+/// assert_eq!(cmp_case_insensitive("apple", "Banana"), Ordering::Less);
+/// assert_eq!(cmp_case_insensitive("Apple", "apple"), Ordering::Equal);
+/// ```
+pub fn cmp_case_insensitive(a: &str, b: &str) -> Ordering {
+    a.chars()
+        .flat_map(char::to_lowercase)
+        .cmp(b.chars().flat_map(char::to_lowercase))
+}
+
+/// Combination of [`cmp_natural`] and [`cmp_case_insensitive`].
+///
+/// ```
+/// # use leptos_struct_table::cmp_natural_case_insensitive;
+/// # use std::cmp::Ordering;
+/// assert_eq!(cmp_natural_case_insensitive("Item2", "item10"), Ordering::Less);
+/// assert_eq!(cmp_natural_case_insensitive("ITEM10", "item10"), Ordering::Equal);
+/// ```
+pub fn cmp_natural_case_insensitive(a: &str, b: &str) -> Ordering {
+    cmp_natural_by(a, b, |a, b| a.to_lowercase().cmp(b.to_lowercase()))
+}
+
+fn cmp_natural_by(a: &str, b: &str, cmp_chars: impl Fn(char, char) -> Ordering) -> Ordering {
+    let mut a = a.chars().peekable();
+    let mut b = b.chars().peekable();
+
+    loop {
+        match (a.peek().copied(), b.peek().copied()) {
+            (None, None) => return Ordering::Equal,
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(char_a), Some(char_b)) if char_a.is_ascii_digit() && char_b.is_ascii_digit() => {
+                let ordering = cmp_digits(&take_digits(&mut a), &take_digits(&mut b));
+                if ordering != Ordering::Equal {
+                    return ordering;
+                }
+            }
+            (Some(char_a), Some(char_b)) => {
+                let ordering = cmp_chars(char_a, char_b);
+                if ordering != Ordering::Equal {
+                    return ordering;
+                }
+                a.next();
+                b.next();
+            }
+        }
+    }
+}
+
+fn take_digits(chars: &mut Peekable<Chars>) -> String {
+    let mut digits = String::new();
+
+    while let Some(digit) = chars.next_if(char::is_ascii_digit) {
+        digits.push(digit);
+    }
+
+    digits
+}
+
+/// Compares two runs of digits by their numeric value without parsing them so they can't overflow.
+/// With the same value the one with fewer leading zeros comes first.
+fn cmp_digits(a: &str, b: &str) -> Ordering {
+    let trimmed_a = a.trim_start_matches('0');
+    let trimmed_b = b.trim_start_matches('0');
+
+    trimmed_a
+        .len()
+        .cmp(&trimmed_b.len())
+        .then_with(|| trimmed_a.cmp(trimmed_b))
+        .then_with(|| a.len().cmp(&b.len()))
+}
+
+/// Options for [`LocaleCollator`].
+#[derive(Clone, Debug, Default)]
+pub struct LocaleCollatorOptions {
+    numeric: bool,
+    case_insensitive: bool,
+}
+
+impl LocaleCollatorOptions {
+    /// Compares runs of digits by their numeric value like [`cmp_natural`]. Defaults to `false`.
+    pub fn numeric(mut self, numeric: bool) -> Self {
+        self.numeric = numeric;
+        self
+    }
+
+    /// Ignores upper and lower case like [`cmp_case_insensitive`]. Defaults to `false`.
+    pub fn case_insensitive(mut self, case_insensitive: bool) -> Self {
+        self.case_insensitive = case_insensitive;
+        self
+    }
+}
+
+/// Compares strings according to the rules of a language using the browser's
+/// [`Intl.Collator`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/Collator).
+/// For example in German `"ä"` is sorted next to `"a"` instead of after `"z"`.
+///
+/// Create it once and reuse it for all comparisons of a sort because creating it is comparatively expensive.
+/// This only works in the browser.
+///
+/// ```no_run
+/// # use leptos_struct_table::*;
+/// let collator = LocaleCollator::new("de", LocaleCollatorOptions::default().numeric(true));
+///
+/// let mut names = vec!["Zoe", "Ärger", "Anna"];
+/// names.sort_by(|a, b| collator.compare(a, b));
+/// assert_eq!(names, vec!["Anna", "Ärger", "Zoe"]);
+/// ```
+pub struct LocaleCollator {
+    compare: js_sys::Function,
+}
+
+impl LocaleCollator {
+    /// Creates a collator for the given locale (e.g. `"en-US"`). Pass an empty string for the locale of the browser.
+    pub fn new(locale: &str, options: LocaleCollatorOptions) -> Self {
+        let locales = js_sys::Array::new();
+        if !locale.is_empty() {
+            locales.push(&JsValue::from_str(locale));
+        }
+
+        let js_options = js_sys::Object::new();
+        let _ = js_sys::Reflect::set(
+            &js_options,
+            &"numeric".into(),
+            &JsValue::from_bool(options.numeric),
+        );
+        if options.case_insensitive {
+            let _ = js_sys::Reflect::set(&js_options, &"sensitivity".into(), &"accent".into());
+        }
+
+        let collator = js_sys::Intl::Collator::new(&locales, &js_options);
+
+        Self {
+            compare: collator.compare(),
+        }
+    }
+
+    /// Compares the two strings.
+    pub fn compare(&self, a: &str, b: &str) -> Ordering {
+        let result = self
+            .compare
+            .call2(&JsValue::NULL, &JsValue::from_str(a), &JsValue::from_str(b))
+            .ok()
+            .and_then(|result| result.as_f64())
+            .unwrap_or_default();
+
+        result.partial_cmp(&0.0).unwrap_or(Ordering::Equal)
+    }
+}
@@ -1,6 +1,9 @@
 #![allow(unused_variables)]
 
-use crate::CellValue;
+use crate::{
+    use_cell_error, use_cell_error_class, use_cell_error_message_class, use_cell_selected,
+    use_cell_selection_class, use_cell_tabindex, CellValue, Selection,
+};
 use std::marker::PhantomData;
 
 use leptos::prelude::*;
@@ -24,7 +27,31 @@ where
     T: CellValue<M> + Send + Sync + Clone + 'static,
     M: 'static,
 {
+    let error = use_cell_error(row, index);
+    let error_class = use_cell_error_class(row, index);
+    let error_message_class = use_cell_error_message_class();
+    let selection_class = use_cell_selection_class(index);
+    let tabindex = use_cell_tabindex(index);
+    let selected = use_cell_selected(index);
+    // cells are only selectable with a cell selection
+    let selectable = matches!(use_context::<Selection>(), Some(Selection::Cells(_)));
+
     view! {
-        <td class=class>{move || value.get().render_value(options.clone())}</td>
+        <td
+            class=move || {
+                format!("{class} {} {}", selection_class.read(), error_class.read())
+            }
+            role="gridcell"
+            tabindex=tabindex
+            aria-selected=move || selectable.then(|| selected.get().to_string())
+            aria-invalid=move || error.read().is_some().then_some("true")
+            title=error
+        >
+            {move || value.get().render_value(options.clone())}
+            {move || {
+                let error_message_class = error_message_class.clone();
+                error.get().map(|error| view! { <span class=error_message_class>{error}</span> })
+            }}
+        </td>
     }
 }
@@ -1,11 +1,13 @@
 mod cell;
-mod renderer_fn;
+mod pager;
+pub(crate) mod renderer_fn;
 mod row;
 mod table_content;
 mod tbody;
 mod thead;
 
 pub use cell::*;
+pub use pager::*;
 pub use row::*;
 pub use table_content::*;
 pub use tbody::*;
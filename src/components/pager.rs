@@ -0,0 +1,124 @@
+use crate::{PaginationController, TableClassesProvider};
+use leptos::prelude::*;
+use std::marker::PhantomData;
+use std::ops::Range;
+
+/// A pager for tables with [`DisplayStrategy::Pagination`](crate::DisplayStrategy::Pagination).
+///
+/// Renders which rows are shown (like "11–20 of 95") together with buttons for the first, previous, next and
+/// last page and the pages around the current one. It brings no styles of its own. All classes come from
+/// the classes provider `ClsP`.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_struct_table::*;
+/// # fn demo() -> impl IntoView {
+/// let controller = PaginationController::default();
+///
+/// view! {
+///     <Pager<TailwindClassesPreset> controller />
+/// }
+/// # }
+/// ```
+#[component]
+pub fn Pager<ClsP>(
+    /// The controller that is passed to [`DisplayStrategy::Pagination`](crate::DisplayStrategy::Pagination).
+    controller: PaginationController,
+    /// The number of page buttons that are shown on each side of the current page. Defaults to `2`.
+    #[prop(default = 2)]
+    sibling_count: usize,
+    /// Additional classes to add to the root element
+    #[prop(optional, into)]
+    class: Signal<String>,
+    #[prop(optional)] _marker: PhantomData<ClsP>,
+) -> impl IntoView
+where
+    ClsP: TableClassesProvider + Send + Sync + Copy + 'static,
+{
+    let class_provider = ClsP::new();
+
+    let current_page = controller.current_page;
+    let page_count = controller.page_count();
+    let has_previous = controller.has_previous();
+    let has_next = controller.has_next();
+    let page_row_range = controller.page_row_range();
+    let row_count = controller.row_count();
+
+    let info = move || {
+        let Range { start, end } = page_row_range.get();
+
+        match (start < end, row_count.get()) {
+            (true, Some(row_count)) => format!("{}–{} of {}", start + 1, end, row_count),
+            (true, None) => format!("{}–{}", start + 1, end),
+            (false, row_count) => format!("0 of {}", row_count.unwrap_or_default()),
+        }
+    };
+
+    let pages = move || page_window(current_page.get(), page_count.get(), sibling_count);
+
+    let button = move |label: &'static str,
+                       aria_label: &'static str,
+                       disabled: Signal<bool>,
+                       on_click: fn(&PaginationController)| {
+        view! {
+            <button
+                type="button"
+                class=move || class_provider.pager_button(false, disabled.get())
+                aria-label=aria_label
+                disabled=disabled
+                on:click=move |_| on_click(&controller)
+            >
+                {label}
+            </button>
+        }
+    };
+
+    let no_previous = Signal::derive(move || !has_previous.get());
+    let no_next = Signal::derive(move || !has_next.get());
+    let no_last = Signal::derive(move || !has_next.get() || page_count.get().is_none());
+
+    view! {
+        <nav class=move || class_provider.pager(&class.read()) aria-label="Pagination">
+            <span class=class_provider.pager_info() aria-live="polite">
+                {info}
+            </span>
+            <div>
+                {button("«", "First page", no_previous, PaginationController::first)}
+                {button("‹", "Previous page", no_previous, PaginationController::previous)}
+                <For each=pages key=|page| *page let:page>
+                    <button
+                        type="button"
+                        class=move || class_provider.pager_button(current_page.get() == page, false)
+                        aria-current=move || (current_page.get() == page).then_some("page")
+                        on:click=move |_| controller.go_to(page)
+                    >
+                        {page + 1}
+                    </button>
+                </For>
+                {button("›", "Next page", no_next, PaginationController::next)}
+                {button("»", "Last page", no_last, PaginationController::last)}
+            </div>
+        </nav>
+    }
+}
+
+/// The pages around the current one that get a button.
+fn page_window(
+    current_page: usize,
+    page_count: Option<usize>,
+    sibling_count: usize,
+) -> Range<usize> {
+    let button_count = sibling_count * 2 + 1;
+
+    let mut start = current_page.saturating_sub(sibling_count);
+    let mut end = start + button_count;
+
+    if let Some(page_count) = page_count {
+        if end > page_count {
+            end = page_count;
+            start = end.saturating_sub(button_count);
+        }
+    }
+
+    start..end
+}
@@ -1,5 +1,5 @@
 use crate::table_row::TableRow;
-use crate::EventHandler;
+use crate::{render_column_window, EventHandler, LoadMoreState, Selection};
 use leptos::prelude::*;
 
 /// The default table row renderer. Uses the `<tr>` element. Please note that this
@@ -20,8 +20,20 @@ pub fn DefaultTableRowRenderer<Row>(
 where
     Row: TableRow + 'static,
 {
+    // rows are only selectable with a row selection
+    let selectable = matches!(
+        use_context::<Selection>(),
+        Some(Selection::Single(_) | Selection::Multiple(_))
+    );
+
     view! {
-        <tr class=class on:click=move |mouse_event| on_select.run(mouse_event)>
+        <tr
+            class=class
+            role="row"
+            aria-rowindex=aria_row_index(index)
+            aria-selected=move || selectable.then(|| selected.get().to_string())
+            on:click=move |mouse_event| on_select.run(mouse_event)
+        >
             {TableRow::render_row(row, index)}
         </tr>
     }
@@ -31,14 +43,31 @@ where
 /// appropriate height. This is used in place of rows that are not shown
 /// before and after the currently visible rows.
 pub fn DefaultRowPlaceholderRenderer(height: Signal<f64>) -> impl IntoView {
-    view! { <tr style:height=move || format!("{}px", height.get()) style="display: block"></tr> }
+    view! {
+        <tr
+            style:height=move || format!("{}px", height.get())
+            style="display: block"
+            role="presentation"
+            aria-hidden="true"
+        ></tr>
+    }
+}
+
+/// The value of the `aria-rowindex` attribute for the body row with the given index.
+/// It starts at 1 and counts the head row.
+pub fn aria_row_index(index: usize) -> usize {
+    index + 2
 }
 
 /// The default error row renderer which just displays the error message when
 /// a row fails to load, i.e. when [`TableDataProvider::get_rows`] returns an `Err(..)`.
 #[allow(unused_variables)]
 pub fn DefaultErrorRowRenderer(err: String, index: usize, col_count: usize) -> impl IntoView {
-    view! { <tr><td colspan=col_count>{err}</td></tr> }
+    view! {
+        <tr role="row" aria-rowindex=aria_row_index(index)>
+            <td role="gridcell" colspan=col_count>{err}</td>
+        </tr>
+    }
 }
 
 /// The default loading row renderer which just displays a loading indicator.
@@ -51,15 +80,42 @@ pub fn DefaultLoadingRowRenderer(
     col_count: usize,
 ) -> impl IntoView {
     view! {
-        <tr class=class>
+        <tr class=class role="row" aria-rowindex=aria_row_index(index) aria-busy="true">
             {
-                (0..col_count).map(|col_index| view! {
-                    <td class=get_cell_class.run((col_index,))>
+                render_column_window(col_count, move |col_index| view! {
+                    <td class=get_cell_class.run((col_index,)) role="gridcell">
                         <div class=get_inner_cell_class.run((col_index,))></div>
                         " "
                     </td>
-                }).collect_view()
+                })
             }
         </tr>
     }
 }
+
+/// The default renderer of the "Load more" row of [`DisplayStrategy::LoadMore`](crate::DisplayStrategy::LoadMore).
+/// It displays a button that loads the next rows, a loading indicator or a note that there are no more rows.
+pub fn DefaultLoadMoreRenderer(
+    state: Signal<LoadMoreState>,
+    on_load_more: Callback<()>,
+    col_count: usize,
+) -> impl IntoView {
+    view! {
+        <tr role="row" data-load-more="">
+            <td role="gridcell" colspan=col_count>
+                {move || match state.get() {
+                    LoadMoreState::More => {
+                        view! {
+                            <button type="button" on:click=move |_| on_load_more.run(())>
+                                "Load more"
+                            </button>
+                        }
+                            .into_any()
+                    }
+                    LoadMoreState::Loading => "Loading…".into_any(),
+                    LoadMoreState::End => "No more rows".into_any(),
+                }}
+            </td>
+        </tr>
+    }
+}
@@ -2,24 +2,30 @@
 
 #![allow(clippy::await_holding_refcell_ref)]
 
+use crate::clipboard::{is_editing, CellErrorContext, ClipboardSource};
+use crate::column_virtualization::initial_column_widths;
 use crate::components::renderer_fn::renderer_fn;
+use crate::keyboard::{FocusedCellContext, KeyBinding, KeyBindings};
 use crate::loaded_rows::{LoadedRows, RowState};
-use crate::selection::Selection;
+use crate::selection::{CellPosition, CellSelectionContext, Selection};
 use crate::table_row::TableRow;
 use crate::{
-    ChangeEvent, ColumnSort, DefaultErrorRowRenderer, DefaultLoadingRowRenderer,
+    compute_column_window, ChangeEvent, ColumnSort, ColumnVirtualization, ColumnWidths,
+    ColumnWindow, DefaultErrorRowRenderer, DefaultLoadMoreRenderer, DefaultLoadingRowRenderer,
     DefaultRowPlaceholderRenderer, DefaultTableBodyRenderer, DefaultTableHeadRenderer,
     DefaultTableHeadRowRenderer, DefaultTableRowRenderer, DisplayStrategy, EventHandler,
-    ReloadController, RowReader, SelectionChangeEvent, SortingMode, TableClassesProvider,
-    TableDataProvider, TableHeadEvent,
+    LoadMoreState, ReloadController, RowHeights, RowIndex, RowReader, ScrollAlign,
+    SelectionChangeEvent, SortingMode, SortingOptions, TableAction, TableAnnouncement,
+    TableClassesProvider, TableClipboard, TableController, TableDataProvider, TableHeadEvent,
 };
 use leptos::prelude::*;
 use leptos::tachys::view::any_view::AnyView;
 use leptos::task::spawn_local;
 use leptos_use::core::IntoElementMaybeSignal;
 use leptos_use::{
-    use_debounce_fn, use_element_size_with_options, use_scroll_with_options, UseElementSizeOptions,
-    UseElementSizeReturn, UseScrollOptions, UseScrollReturn,
+    use_document, use_element_size_with_options, use_event_listener, use_resize_observer,
+    use_scroll_with_options, UseElementSizeOptions, UseElementSizeReturn, UseScrollOptions,
+    UseScrollReturn,
 };
 use std::cell::RefCell;
 use std::collections::{HashSet, VecDeque};
@@ -28,6 +34,7 @@ use std::marker::PhantomData;
 use std::ops::Range;
 use std::rc::Rc;
 use std::sync::Arc;
+use wasm_bindgen::JsCast;
 
 const MAX_DISPLAY_ROW_COUNT: usize = 500;
 
@@ -68,6 +75,15 @@ renderer_fn!(
     default DefaultLoadingRowRenderer
 );
 
+renderer_fn!(
+    LiveRegionRendererFn(announcement: Signal<String>)
+);
+
+renderer_fn!(
+    LoadMoreRendererFn(state: Signal<LoadMoreState>, on_load_more: Callback<()>, col_count: usize)
+    default DefaultLoadMoreRenderer
+);
+
 /// Render the content of a table. This is the main component of this crate.
 #[component]
 pub fn TableContent<Row, DataP, Err, ClsP, ScrollEl, ScrollM>(
@@ -84,6 +100,7 @@ pub fn TableContent<Row, DataP, Err, ClsP, ScrollEl, ScrollM>(
     /// - `None` - No selection (default)
     /// - `Single` - Single selection
     /// - `Multiple` - Multiple selection
+    /// - `Cells` - Spreadsheet-style cell range selection
     ///
     /// Please see [`Selection`] for more information and check out the
     /// [selectable example](https://github.com/Synphonyte/leptos-struct-table/blob/master/examples/selectable/src/main.rs).
@@ -124,6 +141,15 @@ pub fn TableContent<Row, DataP, Err, ClsP, ScrollEl, ScrollM>(
     /// before and after the currently visible rows.
     #[prop(optional, into)]
     row_placeholder_renderer: RowPlaceholderRendererFn,
+    /// The renderer of the "Load more" row that is shown below the rows with [`DisplayStrategy::LoadMore`].
+    /// Defaults to [`DefaultLoadMoreRenderer`].
+    #[prop(optional, into)]
+    load_more_renderer: LoadMoreRendererFn,
+    /// The renderer of the live region that announces sorting changes and loading to screen readers.
+    /// Defaults to `None` which renders no live region. Pass [`DefaultLiveRegionRenderer`](crate::DefaultLiveRegionRenderer) to render a visually
+    /// hidden `<caption>` or your own renderer, for example if you already render a caption.
+    #[prop(optional, into)]
+    live_region_renderer: Option<LiveRegionRendererFn>,
     /// Additional classes to add to rows
     #[prop(optional, into)]
     row_class: Signal<String>,
@@ -145,13 +171,24 @@ pub fn TableContent<Row, DataP, Err, ClsP, ScrollEl, ScrollM>(
     /// The sorting to apply to the table.
     /// For this to work you have add `#[table(sortable)]` to your struct.
     /// Please see the [simple example](https://github.com/Synphonyte/leptos-struct-table/blob/master/examples/simple/src/main.rs).
-    #[prop(default = RwSignal::new(VecDeque::new()), into)]
-    sorting: RwSignal<VecDeque<(usize, ColumnSort)>>,
+    /// If not given, the table starts with the `default_sorting`.
+    #[prop(optional, into)]
+    sorting: Option<RwSignal<VecDeque<(usize, ColumnSort)>>>,
+    /// The initial sorting if no `sorting` is given. [`TableController::reset_sorting`] restores it.
+    /// Use [`sorting_by_name`](crate::sorting_by_name) to build it from the column names. Defaults to [`TableRow::default_sorting`]
+    /// which is declared with the field attributes `default_sort` and `default_sort_priority`.
+    #[prop(optional, into)]
+    default_sorting: Option<VecDeque<(usize, ColumnSort)>>,
     /// The sorting mode to use. Defaults to `MultiColumn`. Please note that
     /// this to have any effect you have to add the macro attribute `#[table(sortable)]`
     /// to your struct.
     #[prop(optional)]
     sorting_mode: SortingMode,
+    /// How clicking the column headers changes the sorting: the first sort direction per column,
+    /// the maximum number of sorted columns and whether columns can go back to unsorted.
+    /// Please see [`SortingOptions`].
+    #[prop(optional)]
+    sorting_options: SortingOptions,
     /// This is called once the number of rows is known.
     /// It will only be executed if [`TableDataProvider::row_count`] returns `Some(...)`.
     ///
@@ -168,12 +205,19 @@ pub fn TableContent<Row, DataP, Err, ClsP, ScrollEl, ScrollM>(
     /// The display strategy to use when rendering the table.
     /// Can be one of
     /// - `Virtualization`
+    /// - `BottomAnchored`
     /// - `InfiniteScroll`
+    /// - `LoadMore`
     /// - `Pagination`
     ///
     /// Please check [`DisplayStrategy`] to see explanations of all available options.
     #[prop(optional)]
     display_strategy: DisplayStrategy,
+    /// The height of every row in pixels. If set, the rows are not measured and the visible rows as well as the
+    /// height of the placeholders are computed from it. This is faster and keeps the scroll position stable.
+    /// Defaults to `None` which measures the rendered rows so they can have different heights.
+    #[prop(optional, into)]
+    row_height: Option<f64>,
     /// The maximum number of loading rows to display. Defaults to `None` which means unlimited.
     /// Use this if you load a small number of rows and don't want the entire screen to be full of
     /// loading rows.
@@ -182,6 +226,25 @@ pub fn TableContent<Row, DataP, Err, ClsP, ScrollEl, ScrollM>(
     /// Provides access to the data rows.
     #[prop(optional)]
     row_reader: RowReader<Row>,
+    /// Allows to scroll to rows programmatically and to read which rows are visible.
+    /// See [`TableController`] for details.
+    #[prop(optional)]
+    controller: TableController<Row>,
+    /// Formats the messages that are announced to screen readers when the sorting changes or rows are loaded.
+    /// Defaults to [`TableAnnouncement::default_message`]. Use this to translate them.
+    #[prop(optional, into)]
+    format_announcement: Option<Callback<TableAnnouncement, String>>,
+    /// The keys of the keyboard navigation. Defaults to [`KeyBindings::default()`].
+    /// Pass [`KeyBindings::new()`] to disable the keyboard navigation.
+    #[prop(optional)]
+    key_bindings: KeyBindings,
+    /// Copies the selected rows to the clipboard on Ctrl+C. See [`TableClipboard`] for details.
+    #[prop(optional, into)]
+    clipboard: Option<TableClipboard<Row>>,
+    /// Only renders the columns that are visible in the scroll container.
+    /// Defaults to `None` which renders all columns. See [`ColumnVirtualization`] for details.
+    #[prop(optional, into)]
+    column_virtualization: Option<ColumnVirtualization>,
 
     #[prop(optional)] _marker: PhantomData<(Err, ScrollM)>,
 ) -> impl IntoView
@@ -213,9 +276,24 @@ where
             loaded_rows.read()[index].clone()
         }));
 
+    let _ = controller.get_loaded_rows.replace(Box::new(move || {
+        loaded_rows.with_untracked(|loaded_rows| {
+            (0..loaded_rows.len())
+                .filter_map(|index| loaded_rows.get(index).cloned())
+                .collect()
+        })
+    }));
+
+    let default_sorting = default_sorting.unwrap_or_else(Row::default_sorting);
+    let sorting = sorting.unwrap_or_else(|| RwSignal::new(default_sorting.clone()));
+    controller
+        .sorting
+        .set_value(Some((sorting, default_sorting)));
+
     let first_selected_index = RwSignal::new(None::<usize>);
 
-    let (row_count, set_row_count) = signal(None::<usize>);
+    let (row_count, set_row_count) = controller.row_count_signal.split();
+    set_row_count.set(None);
 
     let set_known_row_count = move |row_count: usize| {
         set_row_count.set(Some(row_count));
@@ -253,6 +331,65 @@ where
         }
     };
 
+    if let Selection::Cells(cell_selection) = selection {
+        provide_context(CellSelectionContext {
+            cell_selection,
+            selected_class: Callback::new(move |(row_index, col_index)| {
+                class_provider.selected_cell(row_index, col_index)
+            }),
+            anchor_class: Callback::new(move |(row_index, col_index)| {
+                class_provider.anchor_cell(row_index, col_index)
+            }),
+        });
+    }
+
+    let focused_cell = RwSignal::new(None::<CellPosition>);
+    // the number of rows that are shown with `DisplayStrategy::LoadMore`
+    let load_more_row_count = match display_strategy {
+        DisplayStrategy::LoadMore { row_count, .. } => row_count.max(1),
+        _ => 0,
+    };
+    let shown_row_count = RwSignal::new(load_more_row_count);
+    // the number of rows that are known to exist as long as the data source doesn't know its row count
+    let discovered_row_count = RwSignal::new(0_usize);
+    // whether the table follows appended rows with `DisplayStrategy::BottomAnchored { tail: true }`
+    let stick_to_bottom = RwSignal::new(matches!(
+        display_strategy,
+        DisplayStrategy::BottomAnchored { tail: true }
+    ));
+    // whether the table is still scrolling to the end it starts at with `DisplayStrategy::BottomAnchored`
+    let scrolling_to_end = RwSignal::new(matches!(
+        display_strategy,
+        DisplayStrategy::BottomAnchored { .. }
+    ));
+    let anchored_to_bottom =
+        Signal::derive(move || stick_to_bottom.get() || scrolling_to_end.get());
+    let row_heights = RwSignal::new(RowHeights::new(row_height.unwrap_or(20.0)));
+    provide_context(FocusedCellContext(focused_cell));
+    provide_context(selection);
+
+    // the message of the live region that is read by screen readers
+    let (announcement, set_announcement) = signal(String::new());
+    let announce = move |table_announcement: TableAnnouncement| {
+        set_announcement.set(match format_announcement {
+            Some(format_announcement) => format_announcement.run(table_announcement),
+            None => table_announcement.default_message(),
+        });
+    };
+    // whether the first load after a (re)load was announced already
+    let load_announced = StoredValue::new(false);
+
+    let cell_errors = clipboard.as_ref().map(TableClipboard::cell_errors);
+    if let Some(cell_errors) = cell_errors {
+        provide_context(CellErrorContext {
+            cell_errors,
+            error_class: Callback::new(move |(row_index, col_index)| {
+                class_provider.cell_error(row_index, col_index)
+            }),
+            message_class: class_provider.cell_error_message(),
+        });
+    }
+
     let (reload_count, set_reload_count) = signal(0_usize);
     let clear = {
         let load_row_count = load_row_count.clone();
@@ -260,6 +397,14 @@ where
         move |clear_row_count: bool| {
             selection.clear();
             first_selected_index.set(None);
+            focused_cell.set(None);
+            // the rows at the indices are different now
+            row_heights.update(RowHeights::clear);
+            shown_row_count.set(load_more_row_count);
+            discovered_row_count.set(0);
+            if let Some(cell_errors) = cell_errors {
+                cell_errors.clear();
+            }
             LoadedRows::<Row>::clear(&mut loaded_rows.write());
 
             if clear_row_count {
@@ -270,12 +415,18 @@ where
                 }
             }
 
+            load_announced.set_value(false);
             set_reload_count.set(reload_count.get_untracked().overflowing_add(1).0);
         }
     };
 
+    let sorting_options = sorting_options.with_first_sorts_of::<Row>();
     let on_head_click = move |event: TableHeadEvent| {
-        sorting_mode.update_sorting_from_event(&mut sorting.write(), event);
+        sorting_mode.update_sorting_from_event_with_options(
+            &mut sorting.write(),
+            event,
+            &sorting_options,
+        );
     };
 
     Effect::new({
@@ -302,6 +453,47 @@ where
         }
     });
 
+    // rows were appended: keep everything that is loaded and only fetch the row count again
+    Effect::watch(
+        {
+            let rows = Rc::clone(&rows);
+
+            move || {
+                reload_controller.track_appended();
+                rows.borrow().track_appended();
+            }
+        },
+        {
+            let rows = Rc::clone(&rows);
+            let set_known_row_count = set_known_row_count.clone();
+
+            move |_, _, _| {
+                spawn_local({
+                    let rows = Rc::clone(&rows);
+                    let set_known_row_count = set_known_row_count.clone();
+
+                    async move {
+                        let latest_reload_count = reload_count.get_untracked();
+
+                        let new_row_count = rows.borrow().row_count().await;
+
+                        // check if this component was disposed of or reloaded in the meantime
+                        if reload_count.try_get_untracked() != Some(latest_reload_count) {
+                            return;
+                        }
+
+                        match new_row_count {
+                            Some(new_row_count) => set_known_row_count(new_row_count),
+                            // the end has to be discovered again by loading past it
+                            None => set_row_count.set(None),
+                        }
+                    }
+                });
+            }
+        },
+        false,
+    );
+
     let selected_indices = match selection {
         Selection::None => Signal::stored(HashSet::new()),
         Selection::Single(selected_index) => Signal::derive(move || {
@@ -311,43 +503,50 @@ where
                 .unwrap_or_default()
         }),
         Selection::Multiple(selected_indices) => selected_indices.into(),
+        // rows are never selected as a whole
+        Selection::Cells(_) => Signal::stored(HashSet::new()),
     };
 
     let scroll_container = scroll_container.into_element_maybe_signal();
 
-    let UseScrollReturn { y, set_y, .. } = use_scroll_with_options(
+    let UseScrollReturn {
+        x, set_x, y, set_y, ..
+    } = use_scroll_with_options(
         scroll_container,
         UseScrollOptions::default().throttle(100.0),
     );
 
-    let UseElementSizeReturn { height, .. } = use_element_size_with_options(
+    let UseElementSizeReturn { width, height } = use_element_size_with_options(
         scroll_container,
         UseElementSizeOptions::default().box_(web_sys::ResizeObserverBoxOptions::ContentBox),
     );
 
     Effect::new(move || {
-        if let DisplayStrategy::Virtualization | DisplayStrategy::Pagination { .. } =
-            display_strategy
+        if let DisplayStrategy::Virtualization
+        | DisplayStrategy::BottomAnchored { .. }
+        | DisplayStrategy::LoadMore { .. }
+        | DisplayStrategy::Pagination { .. } = display_strategy
         {
             load_row_count();
         }
     });
 
-    let (average_row_height, set_average_row_height) = signal(20.0);
+    let average_row_height = Memo::new(move |_| row_heights.read().estimated_height());
 
-    let first_visible_row_index = if let DisplayStrategy::Pagination {
-        controller,
-        row_count,
-    } = display_strategy
-    {
-        Memo::new(move |_| controller.current_page.get() * row_count)
-    } else {
-        Memo::new(move |_| (y.get() / average_row_height.get()).floor() as usize)
-    };
-    let visible_row_count = match display_strategy {
-        DisplayStrategy::Pagination { row_count, .. } => Signal::derive(move || row_count),
+    // the number of rows per page if paginated
+    let page_size = display_strategy.page_size();
 
-        DisplayStrategy::Virtualization | DisplayStrategy::InfiniteScroll => {
+    let first_visible_row_index = match (display_strategy, page_size) {
+        (DisplayStrategy::Pagination { controller, .. }, Some(page_size)) => {
+            Memo::new(move |_| controller.current_page.get() * page_size.get())
+        }
+        (DisplayStrategy::LoadMore { .. }, _) => Memo::new(|_| 0),
+        _ => Memo::new(move |_| row_heights.read().index_at(y.get())),
+    };
+    let visible_row_count = match (display_strategy, page_size) {
+        (_, Some(page_size)) => page_size,
+        (DisplayStrategy::LoadMore { .. }, None) => shown_row_count.into(),
+        (_, None) => {
             Memo::new(move |_| ((height.get() / average_row_height.get()).ceil() as usize).max(20))
                 .into()
         }
@@ -355,45 +554,171 @@ where
 
     let (display_range, set_display_range) = signal(0..0);
 
-    let placeholder_height_before =
-        if matches!(display_strategy, DisplayStrategy::Pagination { .. }) {
-            Signal::derive(move || 0.0)
-        } else {
-            Memo::new(move |_| display_range.get().start as f64 * average_row_height.get()).into()
-        };
+    // all rows before and after the displayed ones are on other pages or not loaded yet
+    let without_placeholders = matches!(
+        display_strategy,
+        DisplayStrategy::Pagination { .. } | DisplayStrategy::LoadMore { .. }
+    );
 
-    let placeholder_height_after = if matches!(display_strategy, DisplayStrategy::Pagination { .. })
-    {
+    let placeholder_height_before = if without_placeholders {
+        Signal::derive(move || 0.0)
+    } else {
+        Memo::new(move |_| row_heights.read().offset_of(display_range.get().start)).into()
+    };
+
+    let placeholder_height_after = if without_placeholders {
         Signal::derive(move || 0.0)
     } else {
         Memo::new(move |_| {
-            let row_count_after = if let Some(row_count) = row_count.get() {
-                (row_count.saturating_sub(display_range.get().end)) as f64
-            } else {
-                0.0
+            let row_count = match row_count.get() {
+                Some(row_count) => row_count,
+                // the rows found so far plus one screen so it's always possible to scroll further
+                None if matches!(
+                    display_strategy,
+                    DisplayStrategy::Virtualization | DisplayStrategy::BottomAnchored { .. }
+                ) =>
+                {
+                    discovered_row_count.get() + visible_row_count.get()
+                }
+                None => return 0.0,
             };
 
-            row_count_after * average_row_height.get()
+            let end = display_range.get().end.min(row_count);
+            let row_heights = row_heights.read();
+
+            row_heights.offset_of(row_count) - row_heights.offset_of(end)
         })
         .into()
     };
 
     let tbody_el = RwSignal::new_local(None::<web_sys::Element>);
 
-    let compute_average_row_height = use_debounce_fn(
-        move || {
-            compute_average_row_height_from_loaded(
-                tbody_el,
-                display_range,
-                y,
-                &set_y,
-                set_average_row_height,
-                placeholder_height_before,
-                loaded_rows,
-            );
-        },
-        50.0,
-    );
+    let column_widths = RwSignal::new(Vec::<f64>::new());
+    let column_window = column_virtualization.as_ref().map(|column_virtualization| {
+        column_widths.set(initial_column_widths(
+            &column_virtualization.widths,
+            Row::column_count(),
+        ));
+
+        let overscan = column_virtualization.overscan;
+        let window = Memo::new(move |_| {
+            column_widths
+                .with(|widths| compute_column_window(widths, x.get(), width.get(), overscan))
+        });
+
+        let column_window = ColumnWindow {
+            range: Signal::derive(move || window.read().0.clone()),
+            width_before: Signal::derive(move || window.read().1),
+            width_after: Signal::derive(move || window.read().2),
+        };
+        provide_context(column_window);
+
+        column_window
+    });
+
+    if let (
+        Some(ColumnVirtualization {
+            widths: ColumnWidths::Measured { .. },
+            ..
+        }),
+        Some(column_window),
+    ) = (&column_virtualization, column_window)
+    {
+        Effect::new(move || {
+            column_window.range.track();
+            loaded_rows.track();
+
+            // wait for the cells to be rendered
+            request_animation_frame(move || {
+                measure_column_widths(tbody_el, column_window, column_widths);
+            });
+        });
+    }
+
+    // with a fixed row height everything is computed from it and nothing needs to be measured
+    if row_height.is_none() {
+        // the rendered rows whose heights are observed
+        let rendered_rows = RwSignal::new_local(Vec::<web_sys::Element>::new());
+
+        Effect::new(move || {
+            display_range.track();
+            loaded_rows.track();
+
+            // wait for the rows to be rendered
+            request_animation_frame(move || {
+                let Some(Some(tbody)) = tbody_el.try_get_untracked() else {
+                    return;
+                };
+                let children = tbody.children();
+
+                // skip the "before" and "after" placeholders as well as the "Load more" row
+                let trailing_count = if load_more_row_count > 0 { 2 } else { 1 };
+                let rows = (1..children.length().saturating_sub(trailing_count))
+                    .filter_map(|i| children.item(i))
+                    .collect::<Vec<_>>();
+
+                if rendered_rows.try_with_untracked(|rendered_rows| *rendered_rows != rows)
+                    == Some(true)
+                {
+                    rendered_rows.set(rows);
+                }
+            });
+        });
+
+        let _ = use_resize_observer(rendered_rows, {
+            let set_y = set_y.clone();
+
+            move |entries, _| {
+                let Some(Some(tbody)) = tbody_el.try_get_untracked() else {
+                    return;
+                };
+                let display_start = display_range.get_untracked().start;
+                let prev_placeholder_height_before = placeholder_height_before.get_untracked();
+
+                let changed = loaded_rows.with_untracked(|loaded_rows| {
+                    let mut row_heights = row_heights.write_untracked();
+                    let mut changed = false;
+
+                    for entry in entries {
+                        let tr = entry.target();
+                        // skip the "before" placeholder
+                        let Some(index) = child_position(&tbody, &tr)
+                            .and_then(|position| position.checked_sub(1))
+                            .map(|position| display_start + position)
+                        else {
+                            continue;
+                        };
+
+                        // loading rows have a different height than the actual rows
+                        if let Some(RowState::Loaded(_)) = loaded_rows.get(index) {
+                            let height = tr.get_bounding_client_rect().height();
+                            if height > 0.0 {
+                                changed |= row_heights.set_height(index, height);
+                            }
+                        }
+                    }
+
+                    changed
+                });
+
+                if changed {
+                    row_heights.notify();
+
+                    // keep the visible rows in place if the estimated height of the rows before them changed
+                    // unless the table is anchored to the bottom which is taken care of below
+                    let new_placeholder_height_before = placeholder_height_before.get_untracked();
+                    if prev_placeholder_height_before != new_placeholder_height_before
+                        && !anchored_to_bottom.get_untracked()
+                    {
+                        set_y(
+                            y.get_untracked() - prev_placeholder_height_before
+                                + new_placeholder_height_before,
+                        );
+                    }
+                }
+            }
+        });
+    }
 
     Effect::new(move || {
         // with this a reload triggers this effect
@@ -408,14 +733,25 @@ where
             )
         });
 
-        let visible_count = visible_count.min(MAX_DISPLAY_ROW_COUNT);
+        let load_more = matches!(display_strategy, DisplayStrategy::LoadMore { .. });
+
+        // all rows that were loaded so far are shown with `LoadMore`
+        let visible_count = if load_more {
+            visible_count
+        } else {
+            visible_count.min(MAX_DISPLAY_ROW_COUNT)
+        };
 
         if visible_count == 0 {
             return;
         }
 
-        let mut start = first_visible.saturating_sub(visible_count * 2);
-        let mut end = start + visible_count * 5;
+        let (mut start, mut end) = if load_more {
+            (0, visible_count)
+        } else {
+            let start = first_visible.saturating_sub(visible_count * 2);
+            (start, start + visible_count * 5)
+        };
 
         if let Some(row_count) = row_count_opt {
             // Clamp end to row_count if we know it
@@ -423,13 +759,9 @@ where
 
             // Ensure start is within valid bounds *after* clamping end
             start = start.min(end); // Crucial: prevent start > end
-        } else {
-            //If total number of rows is unknown, we don't clamp,
-            // but limit to MAX_DISPLAY_ROW_COUNT
-            if !matches!(display_strategy, DisplayStrategy::Pagination { .. }) {
-                end = end.min(start + MAX_DISPLAY_ROW_COUNT);
-            }
         }
+        // If the total number of rows is unknown, we don't clamp. The window around the visible rows
+        // is bounded anyway so the number of rendered rows stays the same no matter how far the user scrolls.
 
         if let Some(chunk_size) = DataP::CHUNK_SIZE {
             start = (start / chunk_size) * chunk_size;
@@ -439,10 +771,14 @@ where
         let range = start..end;
 
         set_display_range.set(match display_strategy {
-            DisplayStrategy::Virtualization | DisplayStrategy::InfiniteScroll => range.clone(),
-            DisplayStrategy::Pagination { row_count, .. } => {
-                first_visible..(first_visible + row_count).min(end)
+            DisplayStrategy::Virtualization
+            | DisplayStrategy::BottomAnchored { .. }
+            | DisplayStrategy::InfiniteScroll => range.clone(),
+            DisplayStrategy::Pagination { .. } => {
+                let page_size = page_size.map(|page_size| page_size.get_untracked());
+                first_visible..(first_visible + page_size.unwrap_or_default()).min(end)
             }
+            DisplayStrategy::LoadMore { .. } => 0..visible_count.min(end),
         });
 
         loaded_rows.update_untracked(|loaded_rows| {
@@ -487,7 +823,6 @@ where
 
             // TODO : implement max concurrent requests
             for missing_range in loading_ranges {
-                let compute_average_row_height = compute_average_row_height.clone();
                 spawn_local({
                     let rows = Rc::clone(&rows);
                     let set_known_row_count = set_known_row_count.clone();
@@ -509,6 +844,13 @@ where
                             }
 
                             if let Ok((_, loaded_range)) = &result {
+                                if !loaded_range.is_empty() {
+                                    discovered_row_count.update(|discovered_row_count| {
+                                        *discovered_row_count =
+                                            (*discovered_row_count).max(loaded_range.end);
+                                    });
+                                }
+
                                 if loaded_range.end < missing_range.end {
                                     match row_count_opt {
                                         // Use pre-fetched value!
@@ -523,8 +865,17 @@ where
                                     }
                                 }
                             }
+                            if !load_announced.get_value() {
+                                load_announced.set_value(true);
+                                announce(match &result {
+                                    Ok(_) => TableAnnouncement::Loaded {
+                                        row_count: row_count.get_untracked(),
+                                    },
+                                    Err(_) => TableAnnouncement::LoadingFailed,
+                                });
+                            }
+
                             loaded_rows.write().write_loaded(result, missing_range);
-                            compute_average_row_height();
                         }
                     }
                 });
@@ -532,8 +883,102 @@ where
         }
     });
 
+    Effect::watch(
+        move || sorting.read().front().copied(),
+        move |primary_sort, prev_primary_sort, _| {
+            if prev_primary_sort == Some(primary_sort) {
+                return;
+            }
+
+            announce(match *primary_sort {
+                Some((col_index, sort)) if sort != ColumnSort::None => TableAnnouncement::Sorted {
+                    col_index,
+                    col_name: Row::col_name(col_index),
+                    sort,
+                },
+                _ => TableAnnouncement::SortingCleared,
+            });
+        },
+        false,
+    );
+
+    // the table element is rendered by the user who can spread `TableController::grid_attributes` onto it
+    controller.multiselectable.set(matches!(
+        selection,
+        Selection::Multiple(_) | Selection::Cells(_)
+    ));
+
     let thead_content = Row::render_head_row(sorting.into(), on_head_click).into_any();
 
+    let load_more_state = Signal::derive(move || {
+        let shown = shown_row_count.get();
+
+        if row_count.get().is_some_and(|row_count| shown >= row_count) {
+            return LoadMoreState::End;
+        }
+
+        let loading = loaded_rows.with(|loaded_rows| {
+            display_range.with(|display_range| {
+                loaded_rows[display_range.clone()]
+                    .iter()
+                    .any(|row| matches!(row, RowState::Loading))
+            })
+        });
+
+        if loading {
+            LoadMoreState::Loading
+        } else {
+            LoadMoreState::More
+        }
+    });
+
+    let load_more = Callback::new(move |()| {
+        if load_more_state.get_untracked() == LoadMoreState::More {
+            shown_row_count.update(|shown| *shown += load_more_row_count);
+        }
+    });
+
+    if let DisplayStrategy::LoadMore {
+        auto_load: true, ..
+    } = display_strategy
+    {
+        Effect::new(move || {
+            y.track();
+            height.track();
+            if load_more_state.get() != LoadMoreState::More {
+                return;
+            }
+
+            // wait for the rows to be rendered
+            request_animation_frame(move || {
+                let (Some(Some(tbody)), Some(container)) = (
+                    tbody_el.try_get_untracked(),
+                    scroll_container.get_untracked(),
+                ) else {
+                    return;
+                };
+                let Some(load_more_row) = tbody.last_element_child() else {
+                    return;
+                };
+
+                // the container can be taller than the window if the document is scrolled
+                let window_height = window()
+                    .inner_height()
+                    .ok()
+                    .and_then(|height| height.as_f64())
+                    .unwrap_or(f64::INFINITY);
+                let container_bottom = container
+                    .get_bounding_client_rect()
+                    .bottom()
+                    .min(window_height);
+
+                if load_more_row.get_bounding_client_rect().top() <= container_bottom {
+                    load_more.run(());
+                }
+            });
+        });
+    }
+
     let tbody_content = {
         let row_renderer = row_renderer.clone();
         let loading_row_renderer = loading_row_renderer.clone();
@@ -586,6 +1031,8 @@ where
                     move |(i, row)| {
                         match row {
                             RowState::Loaded(row) => {
+                                provide_context(RowIndex(i));
+
                                 let selected_signal = Signal::derive(move || {
                                     selected_indices.read().contains(&i)
                                 });
@@ -599,7 +1046,15 @@ where
                                     let on_selection_change = on_selection_change.clone();
 
                                     move |evt: web_sys::MouseEvent| {
-                                        update_selection(evt, selection, first_selected_index, i);
+                                        let (meta_pressed, shift_pressed) =
+                                            get_keyboard_modifiers(&evt);
+                                        update_selection(
+                                            selection,
+                                            first_selected_index,
+                                            i,
+                                            meta_pressed,
+                                            shift_pressed,
+                                        );
 
                                         let selection_change_event = SelectionChangeEvent {
                                             row: row.into(),
@@ -628,7 +1083,7 @@ where
                                     .run(class_signal, row, i, selected_signal, on_select.into())
                             }
                             RowState::Error(err) => {
-                                error_row_renderer.run(err, i, Row::COLUMN_COUNT)
+                                error_row_renderer.run(err, i, Row::column_count())
                             }
                             RowState::Loading | RowState::Placeholder => {
                                 loading_row_renderer
@@ -653,7 +1108,7 @@ where
                                                 )
                                         }),
                                         i,
-                                        Row::COLUMN_COUNT,
+                                        Row::column_count(),
                                     )
                             }
                         }
@@ -662,10 +1117,509 @@ where
             />
 
             {row_placeholder_renderer.run(placeholder_height_after)}
+
+            {matches!(display_strategy, DisplayStrategy::LoadMore { .. })
+                .then(|| load_more_renderer.run(load_more_state, load_more, Row::column_count()))}
         }
         .into_any()
     };
 
+    if let DisplayStrategy::BottomAnchored { tail } = display_strategy {
+        // whether the user scrolled to the bottom or away from it
+        Effect::watch(
+            move || y.get(),
+            move |_, _, _| {
+                let Some(container) = scroll_container.get_untracked() else {
+                    return;
+                };
+
+                let distance_to_bottom = container.scroll_height() as f64
+                    - container.scroll_top() as f64
+                    - container.client_height() as f64;
+                let at_bottom = distance_to_bottom <= average_row_height.get_untracked();
+
+                if !at_bottom {
+                    stick_to_bottom.set(false);
+                    scrolling_to_end.set(false);
+                } else if tail {
+                    stick_to_bottom.set(true);
+                }
+            },
+            false,
+        );
+
+        // without `tail` appended rows end the scrolling to the end
+        Effect::watch(
+            move || row_count.get(),
+            move |row_count, prev_row_count, _| {
+                if prev_row_count.is_some_and(|prev_row_count| {
+                    prev_row_count.is_some() && prev_row_count != row_count
+                }) {
+                    scrolling_to_end.set(false);
+                }
+            },
+            false,
+        );
+
+        let set_y = set_y.clone();
+
+        Effect::new(move || {
+            row_count.track();
+            row_heights.track();
+            display_range.track();
+            loaded_rows.track();
+            // without a known row count the end is only an estimate and following it would load all rows
+            if !anchored_to_bottom.get() || row_count.get().is_none() {
+                return;
+            }
+
+            // wait for the rows to be rendered
+            request_animation_frame({
+                let set_y = set_y.clone();
+
+                move || {
+                    if anchored_to_bottom.try_get_untracked() != Some(true) {
+                        return;
+                    }
+                    if let Some(container) = scroll_container.get_untracked() {
+                        set_y(container.scroll_height() as f64);
+                    }
+                }
+            });
+        });
+    }
+
+    // the cell that should receive the focus as soon as its row is rendered
+    let pending_focus = RwSignal::new(None::<CellPosition>);
+
+    // makes sure the row is rendered by showing its page or scrolling to it
+    let show_row = {
+        let set_y = set_y.clone();
+
+        move |row_index: usize| match display_strategy {
+            DisplayStrategy::Pagination { controller, .. } => {
+                let page_size = page_size.map(|page_size| page_size.get_untracked());
+                controller.go_to(row_index / page_size.unwrap_or(1));
+            }
+            DisplayStrategy::LoadMore { .. } => {
+                // load as many more rows as needed to show the row
+                let shown = shown_row_count.get_untracked();
+                if row_index >= shown {
+                    let missing = row_index + 1 - shown;
+                    shown_row_count
+                        .set(shown + missing.div_ceil(load_more_row_count) * load_more_row_count);
+                }
+            }
+            DisplayStrategy::Virtualization
+            | DisplayStrategy::BottomAnchored { .. }
+            | DisplayStrategy::InfiniteScroll => {
+                if !display_range.get_untracked().contains(&row_index) {
+                    set_y(
+                        row_heights.with_untracked(|row_heights| row_heights.offset_of(row_index)),
+                    );
+                }
+            }
+        }
+    };
+
+    let focus_cell = {
+        let show_row = show_row.clone();
+
+        move |cell: CellPosition| {
+            focused_cell.set(Some(cell));
+            pending_focus.set(Some(cell));
+
+            // make sure the column is rendered
+            if let Some(column_window) = column_window {
+                if !column_window
+                    .range
+                    .get_untracked()
+                    .contains(&cell.col_index)
+                {
+                    set_x(
+                        column_widths
+                            .with_untracked(|widths| widths.iter().take(cell.col_index).sum()),
+                    );
+                }
+            }
+
+            show_row(cell.row_index);
+        }
+    };
+
+    // the row that should be scrolled to as soon as it is rendered
+    let pending_scroll = RwSignal::new(None::<(usize, ScrollAlign)>);
+
+    Effect::new(move || {
+        controller.scroll_request.track();
+
+        if let Some((row_index, align)) = controller
+            .scroll_request
+            .try_update_untracked(Option::take)
+            .flatten()
+        {
+            show_row(row_index);
+            pending_scroll.set(Some((row_index, align)));
+        }
+    });
+
+    Effect::new(move || {
+        let Some((row_index, align)) = pending_scroll.get() else {
+            return;
+        };
+        display_range.track();
+        loaded_rows.track();
+
+        // wait for the rows to be rendered
+        request_animation_frame(move || {
+            if pending_scroll.try_get_untracked() != Some(Some((row_index, align))) {
+                return;
+            }
+
+            let loaded = loaded_rows.with_untracked(|loaded_rows| {
+                matches!(loaded_rows.get(row_index), Some(RowState::Loaded(_)))
+            });
+            if !loaded {
+                return;
+            }
+
+            if let Some(el) = row_element(row_index, tbody_el, display_range) {
+                let options = web_sys::ScrollIntoViewOptions::new();
+                options.set_block(align.as_scroll_logical_position());
+                el.scroll_into_view_with_scroll_into_view_options(&options);
+            }
+            pending_scroll.set(None);
+        });
+    });
+
+    let visible_range = Memo::new(move |_| match display_strategy {
+        DisplayStrategy::Pagination { .. } => display_range.get(),
+        DisplayStrategy::Virtualization
+        | DisplayStrategy::BottomAnchored { .. }
+        | DisplayStrategy::InfiniteScroll
+        | DisplayStrategy::LoadMore { .. } => {
+            let row_heights = row_heights.read();
+            let y = y.get();
+
+            let row_count = row_count.get().unwrap_or_else(|| loaded_rows.read().len());
+            let start = row_heights.index_at(y).min(row_count);
+            let end = (row_heights.index_at(y + height.get()) + 1).min(row_count);
+
+            start..end
+        }
+    });
+
+    Effect::new(move || {
+        controller.visible_range_signal.set(visible_range.get());
+    });
+
+    Effect::new(move || {
+        let Some(cell) = pending_focus.get() else {
+            return;
+        };
+        display_range.track();
+        loaded_rows.track();
+        if let Some(column_window) = column_window {
+            column_window.range.track();
+        }
+
+        // wait for the rows to be rendered
+        request_animation_frame(move || {
+            if pending_focus.get_untracked() != Some(cell) {
+                return;
+            }
+
+            let loaded = loaded_rows.with_untracked(|loaded_rows| {
+                matches!(loaded_rows.get(cell.row_index), Some(RowState::Loaded(_)))
+            });
+            if !loaded {
+                return;
+            }
+
+            if let Some(el) = cell_element(cell, tbody_el, display_range, column_window) {
+                let options = web_sys::ScrollIntoViewOptions::new();
+                options.set_block(web_sys::ScrollLogicalPosition::Nearest);
+                options.set_inline(web_sys::ScrollLogicalPosition::Nearest);
+                el.scroll_into_view_with_scroll_into_view_options(&options);
+
+                if let Some(el) = el.dyn_ref::<web_sys::HtmlElement>() {
+                    let _ = el.focus();
+                }
+            }
+            pending_focus.set(None);
+        });
+    });
+
+    let _ = use_event_listener(
+        use_document(),
+        leptos::ev::focusin,
+        move |evt: web_sys::FocusEvent| {
+            if let Some(cell) = event_cell_position(&evt, tbody_el, display_range, column_window) {
+                if focused_cell.get_untracked() != Some(cell) {
+                    focused_cell.set(Some(cell));
+                }
+            }
+        },
+    );
+
+    let _ = use_event_listener(use_document(), leptos::ev::keydown, {
+        let focus_cell = focus_cell.clone();
+        let on_selection_change = on_selection_change.clone();
+
+        move |evt: web_sys::KeyboardEvent| {
+            let target = evt
+                .target()
+                .and_then(|target| target.dyn_into::<web_sys::Element>().ok());
+            // keys inside of inputs are left to them
+            if is_editing(&target) {
+                return;
+            }
+            let Some(cell) = target
+                .as_ref()
+                .and_then(|target| cell_position(target, tbody_el, display_range, column_window))
+            else {
+                return;
+            };
+            let Some(action) = key_bindings.action(&KeyBinding::from_event(&evt)) else {
+                return;
+            };
+
+            evt.prevent_default();
+
+            let last_row_index = row_count
+                .get_untracked()
+                .unwrap_or_else(|| loaded_rows.read_untracked().len())
+                .saturating_sub(1);
+            let last_col_index = Row::column_count().saturating_sub(1);
+            let page_size = visible_row_count.get_untracked().max(1);
+
+            let CellPosition {
+                row_index,
+                col_index,
+            } = cell;
+
+            let new_cell = match action {
+                TableAction::MoveUp | TableAction::ExtendUp => {
+                    CellPosition::new(row_index.saturating_sub(1), col_index)
+                }
+                TableAction::MoveDown | TableAction::ExtendDown => {
+                    CellPosition::new((row_index + 1).min(last_row_index), col_index)
+                }
+                TableAction::MoveLeft | TableAction::ExtendLeft => {
+                    CellPosition::new(row_index, col_index.saturating_sub(1))
+                }
+                TableAction::MoveRight | TableAction::ExtendRight => {
+                    CellPosition::new(row_index, (col_index + 1).min(last_col_index))
+                }
+                TableAction::MoveToRowStart => CellPosition::new(row_index, 0),
+                TableAction::MoveToRowEnd => CellPosition::new(row_index, last_col_index),
+                TableAction::MovePageUp => {
+                    CellPosition::new(row_index.saturating_sub(page_size), col_index)
+                }
+                TableAction::MovePageDown => {
+                    CellPosition::new((row_index + page_size).min(last_row_index), col_index)
+                }
+                TableAction::MoveToFirstCell => CellPosition::new(0, 0),
+                TableAction::MoveToLastCell => CellPosition::new(last_row_index, last_col_index),
+                TableAction::Select => {
+                    match selection {
+                        Selection::None => {}
+                        Selection::Cells(cell_selection) => {
+                            cell_selection.update(|cell_selection| cell_selection.select(cell));
+                        }
+                        Selection::Single(_) | Selection::Multiple(_) => {
+                            // like a Ctrl+Click so multiple rows can be selected with the keyboard
+                            update_selection(
+                                selection,
+                                first_selected_index,
+                                row_index,
+                                true,
+                                false,
+                            );
+
+                            if let Some(RowState::Loaded(row)) =
+                                loaded_rows.read_untracked().get(row_index)
+                            {
+                                on_selection_change.run(SelectionChangeEvent {
+                                    row: (*row).into(),
+                                    row_index,
+                                    selected: selected_indices
+                                        .read_untracked()
+                                        .contains(&row_index),
+                                });
+                            }
+                        }
+                    }
+                    return;
+                }
+            };
+
+            if let Selection::Cells(cell_selection) = selection {
+                cell_selection.update(|cell_selection| match action {
+                    TableAction::ExtendUp
+                    | TableAction::ExtendDown
+                    | TableAction::ExtendLeft
+                    | TableAction::ExtendRight => cell_selection.extend_to(new_cell),
+                    _ => cell_selection.select(new_cell),
+                });
+            }
+
+            focus_cell(new_cell);
+        }
+    });
+
+    if let Selection::Cells(cell_selection) = selection {
+        let dragging = StoredValue::new(false);
+
+        let _ = use_event_listener(
+            use_document(),
+            leptos::ev::mousedown,
+            move |evt: web_sys::MouseEvent| {
+                let Some(cell) = event_cell_position(&evt, tbody_el, display_range, column_window)
+                else {
+                    return;
+                };
+                if evt.button() != 0 {
+                    return;
+                }
+
+                // prevent the browser from selecting text while dragging, except inside of inputs
+                let target = evt
+                    .target()
+                    .and_then(|target| target.dyn_into::<web_sys::Element>().ok());
+                if !is_editing(&target) {
+                    evt.prevent_default();
+                    // preventing the default also prevents the cell from being focused
+                    focus_cell(cell);
+                }
+
+                let (meta_pressed, shift_pressed) = get_keyboard_modifiers(&evt);
+                cell_selection.update(|cell_selection| {
+                    if shift_pressed {
+                        cell_selection.extend_to(cell);
+                    } else if meta_pressed {
+                        cell_selection.add(cell);
+                    } else {
+                        cell_selection.select(cell);
+                    }
+                });
+
+                dragging.set_value(true);
+            },
+        );
+
+        let _ = use_event_listener(
+            use_document(),
+            leptos::ev::mouseover,
+            move |evt: web_sys::MouseEvent| {
+                if !dragging.get_value() {
+                    return;
+                }
+
+                if let Some(cell) =
+                    event_cell_position(&evt, tbody_el, display_range, column_window)
+                {
+                    if cell_selection.read_untracked().focus() != Some(cell) {
+                        cell_selection.update(|cell_selection| cell_selection.extend_to(cell));
+                    }
+                }
+            },
+        );
+
+        let _ = use_event_listener(use_document(), leptos::ev::mouseup, move |_| {
+            dragging.set_value(false);
+        });
+    }
+
+    if let Some(clipboard) = clipboard {
+        let displayed_row = move |index: usize| {
+            if !display_range.get_untracked().contains(&index) {
+                return None;
+            }
+
+            loaded_rows.with_untracked(|loaded_rows| match loaded_rows.get(index) {
+                Some(RowState::Loaded(row)) => Some(*row),
+                _ => None,
+            })
+        };
+
+        *clipboard.source.borrow_mut() = Some(ClipboardSource {
+            selected_rows: Box::new(move || {
+                let indices = match selection {
+                    Selection::Cells(cell_selection) => cell_selection
+                        .read_untracked()
+                        .active_range()
+                        .map(|range| range.rows().collect())
+                        .unwrap_or_default(),
+                    _ => {
+                        let mut indices = selected_indices
+                            .get_untracked()
+                            .into_iter()
+                            .collect::<Vec<_>>();
+                        indices.sort_unstable();
+                        indices
+                    }
+                };
+
+                loaded_rows.with_untracked(|loaded_rows| {
+                    indices
+                        .into_iter()
+                        .filter_map(|index| match loaded_rows.get(index) {
+                            Some(RowState::Loaded(row)) => Some(*row),
+                            _ => None,
+                        })
+                        .collect()
+                })
+            }),
+            selected_cols: Box::new(move || match selection {
+                Selection::Cells(cell_selection) => cell_selection
+                    .read_untracked()
+                    .active_range()
+                    .map(|range| range.cols()),
+                _ => None,
+            }),
+            displayed_row: Box::new(displayed_row),
+            focused_cell: Box::new(move || {
+                let active_element = document().active_element();
+
+                match active_element {
+                    Some(element) if element != document().body()?.into() => {
+                        cell_position(&element, tbody_el, display_range, column_window)
+                    }
+                    // nothing is focused so paste into the selected cells
+                    _ => match selection {
+                        Selection::Cells(cell_selection) => {
+                            cell_selection.read_untracked().active_range().map(|range| {
+                                CellPosition::new(*range.rows().start(), *range.cols().start())
+                            })
+                        }
+                        _ => None,
+                    },
+                }
+            }),
+        });
+
+        let _ = use_event_listener(use_document(), leptos::ev::copy, {
+            let clipboard = clipboard.clone();
+
+            move |evt: web_sys::ClipboardEvent| {
+                if clipboard.on_copy(&evt) {
+                    evt.prevent_default();
+                }
+            }
+        });
+
+        let _ = use_event_listener(
+            use_document(),
+            leptos::ev::paste,
+            move |evt: web_sys::ClipboardEvent| {
+                if clipboard.on_paste(&evt) {
+                    evt.prevent_default();
+                }
+            },
+        );
+    }
+
     let tbody_directive = Arc::new(move |el: web_sys::Element, _: ()| {
         tbody_el.set(Some(el));
     });
@@ -673,86 +1627,151 @@ where
     let tbody = tbody_renderer.run(tbody_content, tbody_class, tbody_directive);
 
     view! {
+        {live_region_renderer.map(|live_region_renderer| live_region_renderer.run(announcement.into()))}
+
         {thead_renderer.run(thead_row_renderer.run(thead_content, thead_row_class), thead_class)}
 
         {tbody}
     }
 }
 
-fn compute_average_row_height_from_loaded<Row, ClsP>(
-    tbody_ref: RwSignal<Option<web_sys::Element>, LocalStorage>,
+/// Returns the position of the cell in `tbody` that contains the given element.
+fn cell_position(
+    element: &web_sys::Element,
+    tbody_el: RwSignal<Option<web_sys::Element>, LocalStorage>,
     display_range: ReadSignal<Range<usize>>,
-    y: Signal<f64>,
-    set_y: &impl Fn(f64),
-    set_average_row_height: WriteSignal<f64>,
-    placeholder_height_before: Signal<f64>,
-    loaded_rows: RwSignal<LoadedRows<Row>>,
-) where
-    Row: TableRow<ClassesProvider = ClsP> + Send + Sync + Clone + 'static,
-{
-    if let Some(el) = tbody_ref.get_untracked() {
-        let el: &web_sys::Element = &el;
-        let display_range = display_range.get_untracked();
-        if display_range.end > 0 {
-            let avg_row_height = loaded_rows.with_untracked(|loaded_rows| {
-                let mut loading_row_start_index = None;
-                let mut loading_row_end_index = None;
-
-                for i in display_range.clone() {
-                    if matches!(loaded_rows[i], RowState::Loaded(_) | RowState::Loading) {
-                        if loading_row_start_index.is_none() {
-                            loading_row_start_index = Some(i);
-                        }
-                        loading_row_end_index = Some(i);
-                    } else if loading_row_end_index.is_some() {
-                        break;
-                    }
-                }
+    column_window: Option<ColumnWindow>,
+) -> Option<CellPosition> {
+    let tbody = tbody_el.get_untracked()?;
+    let cell = element.closest("td, th").ok()??;
+    let tr = cell.parent_element()?;
+
+    if tr.parent_element().as_ref() != Some(&tbody) {
+        return None;
+    }
 
-                if let (Some(loading_row_start_index), Some(loading_row_end_index)) =
-                    (loading_row_start_index, loading_row_end_index)
-                {
-                    if loading_row_end_index == loading_row_start_index {
-                        return None;
-                    }
+    // skip first element, because it's the "before" placeholder
+    let row_position = child_position(&tbody, &tr)?.checked_sub(1)?;
+    let col_index = match first_col_index(&tr, column_window) {
+        Some(first_col_index) => first_col_index + child_position(&tr, &cell)?.checked_sub(1)?,
+        None => child_position(&tr, &cell)?,
+    };
 
-                    let children = el.children();
+    Some(CellPosition::new(
+        display_range.get_untracked().start + row_position,
+        col_index,
+    ))
+}
 
-                    // skip first element, because it's the "before" placeholder
-                    let first_loading_row = children
-                        .get_with_index((loading_row_start_index + 1 - display_range.start) as u32);
-                    let last_loading_row = children
-                        .get_with_index((loading_row_end_index + 1 - display_range.start) as u32);
+/// Returns the cell element at the given position if it is rendered.
+fn cell_element(
+    cell: CellPosition,
+    tbody_el: RwSignal<Option<web_sys::Element>, LocalStorage>,
+    display_range: ReadSignal<Range<usize>>,
+    column_window: Option<ColumnWindow>,
+) -> Option<web_sys::Element> {
+    let tr = row_element(cell.row_index, tbody_el, display_range)?;
+
+    let cell_position = match first_col_index(&tr, column_window) {
+        // skip the spacer cell of the columns before the window
+        Some(first_col_index) => cell.col_index.checked_sub(first_col_index)? + 1,
+        None => cell.col_index,
+    };
 
-                    if let (Some(first_loading_row), Some(last_loaded_row)) =
-                        (first_loading_row, last_loading_row)
-                    {
-                        return Some(
-                            (last_loaded_row.get_bounding_client_rect().top()
-                                - first_loading_row.get_bounding_client_rect().top())
-                                / (loading_row_end_index - loading_row_start_index) as f64,
-                        );
-                    }
-                }
+    tr.children().item(cell_position as u32)
+}
 
-                None
-            });
+/// Returns the row element with the given index if it is rendered.
+fn row_element(
+    row_index: usize,
+    tbody_el: RwSignal<Option<web_sys::Element>, LocalStorage>,
+    display_range: ReadSignal<Range<usize>>,
+) -> Option<web_sys::Element> {
+    let tbody = tbody_el.get_untracked()?;
+    let row_position = row_index.checked_sub(display_range.get_untracked().start)?;
 
-            if let Some(avg_row_height) = avg_row_height {
-                let prev_placeholder_height_before = placeholder_height_before.get_untracked();
+    // skip first element, because it's the "before" placeholder
+    tbody.children().item(row_position as u32 + 1)
+}
+
+/// Returns the column index of the first rendered cell if the row is rendered with horizontal virtualization.
+fn first_col_index(tr: &web_sys::Element, column_window: Option<ColumnWindow>) -> Option<usize> {
+    let column_window = column_window?;
+
+    // rows that don't support horizontal virtualization render all cells without spacers
+    tr.first_element_child()?
+        .has_attribute("data-column-spacer")
+        .then(|| column_window.range.get_untracked().start)
+}
+
+/// Updates the widths of the columns in the current window from the first loaded row that renders them.
+fn measure_column_widths(
+    tbody_el: RwSignal<Option<web_sys::Element>, LocalStorage>,
+    column_window: ColumnWindow,
+    column_widths: RwSignal<Vec<f64>>,
+) {
+    let Some(tbody) = tbody_el.get_untracked() else {
+        return;
+    };
+    let rows = tbody.children();
+
+    let Some(tr) = (0..rows.length()).filter_map(|i| rows.item(i)).find(|tr| {
+        tr.get_attribute("aria-busy").is_none()
+            && first_col_index(tr, Some(column_window)).is_some()
+    }) else {
+        return;
+    };
+
+    let range = column_window.range.get_untracked();
+    let cells = tr.children();
+
+    let measured_widths = range
+        .clone()
+        .zip(1..)
+        .filter_map(|(col_index, cell_position)| {
+            let width = cells
+                .item(cell_position)?
+                .get_bounding_client_rect()
+                .width();
+            Some((col_index, width))
+        })
+        .collect::<Vec<_>>();
 
-                set_average_row_height.set(avg_row_height);
+    let changed = column_widths.with_untracked(|widths| {
+        measured_widths.iter().any(|(col_index, width)| {
+            widths
+                .get(*col_index)
+                .is_some_and(|old_width| (old_width - width).abs() > 0.5)
+        })
+    });
 
-                let new_placeholder_height_before = placeholder_height_before.get_untracked();
-                set_y(
-                    y.get_untracked() - prev_placeholder_height_before
-                        + new_placeholder_height_before,
-                );
+    if changed {
+        column_widths.update(|widths| {
+            for (col_index, width) in measured_widths {
+                if let Some(old_width) = widths.get_mut(col_index) {
+                    *old_width = width;
+                }
             }
-        }
+        });
     }
 }
 
+fn event_cell_position(
+    evt: &web_sys::Event,
+    tbody_el: RwSignal<Option<web_sys::Element>, LocalStorage>,
+    display_range: ReadSignal<Range<usize>>,
+    column_window: Option<ColumnWindow>,
+) -> Option<CellPosition> {
+    let target = evt.target()?.dyn_into::<web_sys::Element>().ok()?;
+    cell_position(&target, tbody_el, display_range, column_window)
+}
+
+fn child_position(parent: &web_sys::Element, child: &web_sys::Element) -> Option<usize> {
+    let children = parent.children();
+
+    (0..children.length()).position(|i| children.item(i).as_ref() == Some(child))
+}
+
 fn get_keyboard_modifiers(evt: &web_sys::MouseEvent) -> (bool, bool) {
     let meta_pressed = evt.meta_key() || evt.ctrl_key();
     let shift_pressed = evt.shift_key();
@@ -760,13 +1779,15 @@ fn get_keyboard_modifiers(evt: &web_sys::MouseEvent) -> (bool, bool) {
 }
 
 fn update_selection(
-    evt: web_sys::MouseEvent,
     selection: Selection,
     first_selected_index: RwSignal<Option<usize>>,
     i: usize,
+    meta_pressed: bool,
+    shift_pressed: bool,
 ) {
     match selection {
-        Selection::None => {}
+        // cells are selected by the listeners on the document
+        Selection::None | Selection::Cells(_) => {}
         Selection::Single(selected_index) => {
             if selected_index.get_untracked() == Some(i) {
                 selected_index.set(None);
@@ -776,7 +1797,6 @@ fn update_selection(
         }
         Selection::Multiple(selected_indices) => {
             let mut indices = selected_indices.write();
-            let (meta_pressed, shift_pressed) = get_keyboard_modifiers(&evt);
 
             if meta_pressed {
                 if indices.contains(&i) {
@@ -1,6 +1,7 @@
 use crate::wrapper_render_fn;
 use crate::{ColumnSort, TableHeadEvent};
 use leptos::prelude::*;
+use leptos::tachys::view::any_view::AnyView;
 
 wrapper_render_fn!(
     /// thead
@@ -8,11 +9,21 @@ wrapper_render_fn!(
     thead,
 );
 
-wrapper_render_fn!(
-    /// thead row
-    DefaultTableHeadRowRenderer,
-    tr,
-);
+/// Default thead row renderer. Please note that this is **NOT** a `#[component]`.
+///
+/// # Arguments
+///
+/// * `content` - The content of the renderer. It's like the children of this view.
+/// * `class` - The class attribute that is passed to the root element
+#[allow(non_snake_case)]
+pub fn DefaultTableHeadRowRenderer(content: AnyView, class: Signal<String>) -> impl IntoView {
+    // the head row is the first row of the grid
+    view! {
+        <tr class=class role="row" aria-rowindex="1">
+            {content}
+        </tr>
+    }
+}
 
 /// The default table header renderer. Renders roughly
 /// ```html
@@ -47,6 +58,8 @@ where
 
     view! {
         <th class=class
+            role="columnheader"
+            aria-sort=move || sort_direction.get().as_aria_sort()
             on:click=move |mouse_event| on_click(TableHeadEvent {
                 index,
                 mouse_event,
@@ -60,6 +60,13 @@ pub trait TableDataProvider<Row, Err: Debug = String> {
     fn track(&self) {
         // by default do nothing
     }
+
+    /// Call `.track()` in this method on all signals that change when rows are appended to the end of the data,
+    /// for example the messages of a chat. Unlike [`track`](TableDataProvider::track) this doesn't reload the
+    /// table but keeps the loaded rows, the selection and the focus and only loads the new rows.
+    fn track_appended(&self) {
+        // by default do nothing
+    }
 }
 
 /// A paginated data source. This is meant to provide a more convenient way
@@ -107,6 +114,11 @@ pub trait PaginatedTableDataProvider<Row, Err: Debug = String> {
     fn track(&self) {
         // by default do nothing
     }
+
+    /// Same as [`TableDataProvider::track_appended`]
+    fn track_appended(&self) {
+        // by default do nothing
+    }
 }
 
 impl<Row, Err, D> TableDataProvider<Row, Err> for D
@@ -139,6 +151,10 @@ where
     fn track(&self) {
         PaginatedTableDataProvider::<Row, Err>::track(self)
     }
+
+    fn track_appended(&self) {
+        PaginatedTableDataProvider::<Row, Err>::track_appended(self)
+    }
 }
 
 /// Return `vec[range.start..range.end]` where `range` is clamped to the length of `vec`.
@@ -0,0 +1,212 @@
+//! Support for the [::diesel] query builder.
+//!
+//! Turns the sorting of a table and the range of rows to load into `.then_order_by(...)`, `.limit(...)`
+//! and `.offset(...)` calls on a (boxed) diesel query.
+
+use crate::{ColumnSort, NullsOrder, NullsOrdering, TableRow};
+use ::diesel::backend::Backend;
+use ::diesel::expression::expression_types::NotSelectable;
+use ::diesel::expression::BoxableExpression;
+use ::diesel::helper_types::{Asc, Desc, IsNull};
+use ::diesel::query_dsl::methods::{LimitDsl, OffsetDsl, ThenOrderDsl};
+use ::diesel::ExpressionMethods;
+use std::collections::VecDeque;
+use std::ops::Range;
+
+/// A boxed `ORDER BY` expression like `users::name.asc()` for the table `QS` and the backend `DB`.
+pub type BoxedOrder<'a, QS, DB> = Box<dyn BoxableExpression<QS, DB, SqlType = NotSelectable> + 'a>;
+
+/// Returns the boxed `ORDER BY` expression that sorts by the given column or `None` if `ColumnSort::None`.
+pub fn order_column<'a, C, QS, DB>(column: C, sort: ColumnSort) -> Option<BoxedOrder<'a, QS, DB>>
+where
+    C: ExpressionMethods,
+    DB: Backend,
+    Asc<C>: BoxableExpression<QS, DB, SqlType = NotSelectable> + 'a,
+    Desc<C>: BoxableExpression<QS, DB, SqlType = NotSelectable> + 'a,
+{
+    match sort {
+        ColumnSort::Ascending => Some(Box::new(column.asc())),
+        ColumnSort::Descending => Some(Box::new(column.desc())),
+        ColumnSort::None => None,
+    }
+}
+
+/// Same as [`order_column`] but places the `NULL` values first or last if `nulls_order` is given.
+///
+/// This sorts by `column IS NULL` first which works with every database. Returns an empty `Vec` if `ColumnSort::None`.
+pub fn order_column_with_nulls<'a, C, QS, DB>(
+    column: C,
+    sort: ColumnSort,
+    nulls_order: Option<NullsOrder>,
+) -> Vec<BoxedOrder<'a, QS, DB>>
+where
+    C: ExpressionMethods + Clone,
+    DB: Backend,
+    Asc<C>: BoxableExpression<QS, DB, SqlType = NotSelectable> + 'a,
+    Desc<C>: BoxableExpression<QS, DB, SqlType = NotSelectable> + 'a,
+    Asc<IsNull<C>>: BoxableExpression<QS, DB, SqlType = NotSelectable> + 'a,
+    Desc<IsNull<C>>: BoxableExpression<QS, DB, SqlType = NotSelectable> + 'a,
+{
+    let Some(order) = order_column(column.clone(), sort) else {
+        return vec![];
+    };
+
+    // `false` sorts before `true`
+    let nulls_order: Option<BoxedOrder<'a, QS, DB>> = match nulls_order {
+        Some(NullsOrder::First) => Some(Box::new(column.is_null().desc())),
+        Some(NullsOrder::Last) => Some(Box::new(column.is_null().asc())),
+        None => None,
+    };
+
+    nulls_order.into_iter().chain([order]).collect()
+}
+
+/// Adds the sorting of the table to the query with `.then_order_by(...)`.
+///
+/// `column` maps the column name ([`TableRow::col_name`]) and the sort to an `ORDER BY` expression.
+/// Use [`order_column`] to create it from the typed column of your schema. Columns for which it
+/// returns `None` are not sorted by.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_struct_table::{ColumnSort, TableRow};
+/// use leptos_struct_table::diesel::{order_by_sorting, order_column, paginate};
+/// # use ::diesel::pg::Pg;
+/// # use ::diesel::prelude::*;
+/// # use std::collections::VecDeque;
+/// # use std::ops::Range;
+/// #[derive(TableRow, Clone)]
+/// struct Customer {
+///     name: String,
+///     signed_up_at: i64,
+/// }
+///
+/// diesel::table! {
+///     customers (id) {
+///         id -> Integer,
+///         name -> Text,
+///         created_at -> BigInt,
+///     }
+/// }
+///
+/// fn customers_query(
+///     sorting: &VecDeque<(usize, ColumnSort)>,
+///     range: Range<usize>,
+/// ) -> customers::BoxedQuery<'static, Pg> {
+///     let query = customers::table.into_boxed();
+///
+///     let query = order_by_sorting::<Customer, _, _, _>(query, sorting, |col_name, sort| {
+///         match col_name {
+///             "name" => order_column(customers::name, sort),
+///             "signed_up_at" => order_column(customers::created_at, sort),
+///             _ => None,
+///         }
+///     });
+///
+///     paginate(query, range)
+/// }
+///
+/// let sorting = VecDeque::from([(1, ColumnSort::Descending), (0, ColumnSort::Ascending)]);
+/// let query = customers_query(&sorting, 20..30);
+///
+/// assert_eq!(
+///     diesel::debug_query::<Pg, _>(&query).to_string(),
+///     r#"SELECT "customers"."id", "customers"."name", "customers"."created_at" FROM "customers" ORDER BY "customers"."created_at" DESC, "customers"."name" ASC LIMIT $1 OFFSET $2 -- binds: [10, 20]"#
+/// );
+/// ```
+pub fn order_by_sorting<'a, Row, Q, QS, DB>(
+    mut query: Q,
+    sorting: &VecDeque<(usize, ColumnSort)>,
+    mut column: impl FnMut(&'static str, ColumnSort) -> Option<BoxedOrder<'a, QS, DB>>,
+) -> Q
+where
+    Row: TableRow,
+    Q: ThenOrderDsl<BoxedOrder<'a, QS, DB>, Output = Q>,
+    DB: Backend,
+{
+    for (col_index, sort) in sorting {
+        if let Some(order) = column(Row::col_name(*col_index), *sort) {
+            query = query.then_order_by(order);
+        }
+    }
+
+    query
+}
+
+/// Same as [`order_by_sorting`] but places the `NULL` values of the columns in `nulls_ordering` first or last.
+///
+/// `column` maps the column name, the sort and the placement of `NULL` values to the `ORDER BY` expressions.
+/// Use [`order_column_with_nulls`] to create them from the typed column of your schema. Pass
+/// [`TableRow::nulls_ordering`] to place them like the data provider of `#[table(impl_vec_data_provider)]`.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_struct_table::{ColumnSort, TableRow};
+/// use leptos_struct_table::diesel::{order_by_sorting_with_nulls, order_column_with_nulls};
+/// # use ::diesel::pg::Pg;
+/// # use ::diesel::prelude::*;
+/// # use std::collections::VecDeque;
+/// #[derive(TableRow, Clone)]
+/// struct Task {
+///     name: String,
+///     #[table(nulls = "last")]
+///     due_at: Option<i64>,
+/// }
+///
+/// diesel::table! {
+///     tasks (id) {
+///         id -> Integer,
+///         name -> Text,
+///         due_at -> Nullable<BigInt>,
+///     }
+/// }
+///
+/// let sorting = VecDeque::from([(1, ColumnSort::Descending)]);
+///
+/// let query = order_by_sorting_with_nulls::<Task, _, _, _>(
+///     tasks::table.into_boxed(),
+///     &sorting,
+///     &Task::nulls_ordering(),
+///     |col_name, sort, nulls_order| match col_name {
+///         "name" => order_column_with_nulls(tasks::name, sort, nulls_order),
+///         "due_at" => order_column_with_nulls(tasks::due_at, sort, nulls_order),
+///         _ => vec![],
+///     },
+/// );
+///
+/// assert_eq!(
+///     diesel::debug_query::<Pg, _>(&query).to_string(),
+///     r#"SELECT "tasks"."id", "tasks"."name", "tasks"."due_at" FROM "tasks" ORDER BY ("tasks"."due_at" IS NULL) ASC, "tasks"."due_at" DESC -- binds: []"#
+/// );
+/// ```
+pub fn order_by_sorting_with_nulls<'a, Row, Q, QS, DB>(
+    mut query: Q,
+    sorting: &VecDeque<(usize, ColumnSort)>,
+    nulls_ordering: &NullsOrdering,
+    mut column: impl FnMut(&'static str, ColumnSort, Option<NullsOrder>) -> Vec<BoxedOrder<'a, QS, DB>>,
+) -> Q
+where
+    Row: TableRow,
+    Q: ThenOrderDsl<BoxedOrder<'a, QS, DB>, Output = Q>,
+    DB: Backend,
+{
+    for (col_index, sort) in sorting {
+        let nulls_order = nulls_ordering.get(*col_index);
+
+        for order in column(Row::col_name(*col_index), *sort, nulls_order) {
+            query = query.then_order_by(order);
+        }
+    }
+
+    query
+}
+
+/// Adds `.limit(...)` and `.offset(...)` to the query to load the given range of rows like it's requested by
+/// [`TableDataProvider::get_rows`](crate::TableDataProvider::get_rows).
+pub fn paginate<Q>(query: Q, range: Range<usize>) -> <<Q as LimitDsl>::Output as OffsetDsl>::Output
+where
+    Q: LimitDsl,
+    <Q as LimitDsl>::Output: OffsetDsl,
+{
+    query.limit(range.len() as i64).offset(range.start as i64)
+}
@@ -1,4 +1,5 @@
 use leptos::prelude::*;
+use std::ops::Range;
 
 /// The display acceleration strategy. Defaults to `Virtualization`.
 #[derive(Copy, Clone, Default)]
@@ -7,16 +8,45 @@ pub enum DisplayStrategy {
     /// will seem as if all rows are there.
     ///
     /// If the data provider doesn't know how many rows there are (i.e. [`TableDataProvider::row_count`]
-    /// returns `None`), this will be the same as `InfiniteScroll`.
+    /// returns `None`), the rows slide through a window of a fixed size while the user scrolls, so even
+    /// tens of thousands of rows keep the DOM small. The scrollbar is sized by an estimate of the rows
+    /// found so far plus one screen. It grows as more rows are loaded until the data provider returns
+    /// fewer rows than requested and the row count is known.
     #[default]
     Virtualization,
 
+    /// Like `Virtualization` but the table starts scrolled to the end and stays anchored to the bottom,
+    /// which suits log viewers and message histories that have the newest rows last. Earlier rows are loaded
+    /// when the user scrolls up while the rows on screen stay in place.
+    ///
+    /// If `tail` is `true`, the table follows new rows that are appended by the data provider (i.e. when it
+    /// notifies through [`TableDataProvider::track_appended`](crate::TableDataProvider::track_appended) or
+    /// [`ReloadController::rows_appended`](crate::ReloadController::rows_appended) is called) as long as the
+    /// user is scrolled to the bottom. Scrolling up stops following, scrolling back down resumes it.
+    /// If `tail` is `false`, appended rows are loaded but the table stays where it is.
+    /// Appending keeps the loaded rows, the selection and the focus.
+    ///
+    /// The end is only followed once [`TableDataProvider::row_count`](crate::TableDataProvider::row_count)
+    /// is known (or the end was reached by scrolling) as following an estimated end would load all rows.
+    BottomAnchored { tail: bool },
+
     /// Only the amount of rows specified is shown. Once the user scrolls down,
     /// more rows will be loaded. The scrollbar handle will shrink progressively
     /// as more and more rows are loaded.
     InfiniteScroll,
 
-    // TODO : LoadMore(usize),
+    /// Only the first `row_count` rows are shown. Below them a "Load more" row is rendered that appends the
+    /// next `row_count` rows when clicked. Scrolling will have no effect on what rows are loaded.
+    ///
+    /// If `auto_load` is `true`, the next rows are also appended as soon as the "Load more" row is scrolled
+    /// into view.
+    ///
+    /// Once [`TableDataProvider::get_rows`](crate::TableDataProvider::get_rows) returns fewer rows than
+    /// requested, the end of the data is reached and the "Load more" row shows that there are no more rows.
+    /// The row is rendered by the `load_more_renderer` of [`TableContent`](crate::TableContent) which
+    /// defaults to [`DefaultLoadMoreRenderer`](crate::DefaultLoadMoreRenderer).
+    LoadMore { row_count: usize, auto_load: bool },
+
     /// Only the amount of rows specified is shown at a time. You can use the
     /// `controller` to manipulate which page of rows is shown.
     /// Scrolling will have no effect on what rows are loaded.
@@ -24,6 +54,9 @@ pub enum DisplayStrategy {
     /// > Please note that this will work wether your data source implements
     /// > [`PaginatedTableDataProvider`] or [`TableDataProvider`] directly.
     /// > Also `row_count` can be different from `PaginatedTableDataProvider::PAGE_ROW_COUNT`.
+    ///
+    /// `row_count` is the initial number of rows per page. It can be changed later with
+    /// [`PaginationController::set_page_size`].
     Pagination {
         row_count: usize,
         controller: PaginationController,
@@ -33,27 +66,75 @@ pub enum DisplayStrategy {
 impl DisplayStrategy {
     pub(crate) fn set_row_count(&self, row_count: usize) {
         match self {
-            Self::Pagination {
-                row_count: page_row_count,
-                controller,
-            } => {
-                controller
-                    .page_count_signal
-                    .set(Some(row_count / *page_row_count + 1));
+            Self::Pagination { controller, .. } => {
+                controller.row_count_signal.set(Some(row_count));
             }
             _ => {
                 // do nothing
             }
         }
     }
+
+    /// Returns the `Signal` of the number of rows per page if this is `Pagination`.
+    pub(crate) fn page_size(&self) -> Option<Signal<usize>> {
+        match *self {
+            Self::Pagination {
+                row_count,
+                controller,
+            } => {
+                // the page size of the controller takes precedence if it was changed at runtime
+                if controller.page_size_signal.get_untracked().is_none() {
+                    controller.page_size_signal.set(Some(row_count));
+                }
+
+                Some(Signal::derive(move || {
+                    controller
+                        .page_size_signal
+                        .get()
+                        .unwrap_or(row_count)
+                        .max(1)
+                }))
+            }
+            _ => None,
+        }
+    }
+}
+
+/// The state of the "Load more" row of [`DisplayStrategy::LoadMore`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum LoadMoreState {
+    /// There are more rows that can be loaded.
+    More,
+    /// Rows are being loaded right now.
+    Loading,
+    /// All rows are shown.
+    End,
 }
 
 /// Allows to control what page is displayed as well as reading the page count and current page
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_struct_table::*;
+/// let controller = PaginationController::default();
+/// controller.set_page_size(10);
+///
+/// // usually called by `TableContent` once the number of rows is known
+/// controller.set_row_count(95);
+///
+/// assert_eq!(controller.page_count().get_untracked(), Some(10));
+///
+/// controller.last();
+/// assert_eq!(controller.current_page.get_untracked(), 9);
+/// assert_eq!(controller.page_row_range().get_untracked(), 90..95);
+/// assert!(!controller.has_next().get_untracked());
+/// ```
 #[derive(Copy, Clone)]
 pub struct PaginationController {
     /// The current page. The first page is `0`.
     pub current_page: RwSignal<usize>,
-    page_count_signal: RwSignal<Option<usize>>,
+    pub(crate) page_size_signal: RwSignal<Option<usize>>,
+    pub(crate) row_count_signal: RwSignal<Option<usize>>,
 }
 
 impl Default for PaginationController {
@@ -61,15 +142,16 @@ impl Default for PaginationController {
         Self {
             // the value here doesn't really matter. We'll react only to changes later
             current_page: RwSignal::new(0),
-            page_count_signal: RwSignal::new(None),
+            page_size_signal: RwSignal::new(None),
+            row_count_signal: RwSignal::new(None),
         }
     }
 }
 
 impl PaginationController {
-    /// Call this to go to the next page
+    /// Call this to go to the next page. Stays on the last page if the page count is known.
     pub fn next(&self) {
-        self.current_page.set(self.current_page.get_untracked() + 1);
+        self.go_to(self.current_page.get_untracked() + 1);
     }
 
     /// Call this to go to the previous page
@@ -78,9 +160,103 @@ impl PaginationController {
             .set(self.current_page.get_untracked().saturating_sub(1));
     }
 
+    /// Call this to go to the given page. The first page is `0`. Pages after the last page
+    /// go to the last page if the page count is known.
+    pub fn go_to(&self, page: usize) {
+        let page = match self.page_count().get_untracked() {
+            Some(page_count) => page.min(page_count.saturating_sub(1)),
+            None => page,
+        };
+
+        if self.current_page.get_untracked() != page {
+            self.current_page.set(page);
+        }
+    }
+
+    /// Call this to go to the first page
+    pub fn first(&self) {
+        self.go_to(0);
+    }
+
+    /// Call this to go to the last page. Does nothing as long as the page count is unknown.
+    pub fn last(&self) {
+        if let Some(page_count) = self.page_count().get_untracked() {
+            self.go_to(page_count.saturating_sub(1));
+        }
+    }
+
     /// Returns a `Signal` of the page count once loaded. Depending on your table data provider
     /// this might not be available and thus always be `None`.
     pub fn page_count(&self) -> Signal<Option<usize>> {
-        self.page_count_signal.into()
+        let page_size = self.page_size_signal;
+        let row_count = self.row_count_signal;
+
+        Signal::derive(move || Some(row_count.get()?.div_ceil(page_size.get()?.max(1))))
+    }
+
+    /// Returns a `Signal` of whether there is a page after the current one.
+    /// This is always `true` as long as the page count is unknown.
+    pub fn has_next(&self) -> Signal<bool> {
+        let current_page = self.current_page;
+        let page_count = self.page_count();
+
+        Signal::derive(move || {
+            page_count
+                .get()
+                .is_none_or(|page_count| current_page.get() + 1 < page_count)
+        })
+    }
+
+    /// Returns a `Signal` of whether there is a page before the current one.
+    pub fn has_previous(&self) -> Signal<bool> {
+        let current_page = self.current_page;
+        Signal::derive(move || current_page.get() > 0)
+    }
+
+    /// Returns a `Signal` of the total number of rows once loaded. Depending on your table data provider
+    /// this might not be available and thus always be `None`.
+    pub fn row_count(&self) -> Signal<Option<usize>> {
+        self.row_count_signal.into()
+    }
+
+    /// Sets the total number of rows. This is done by [`TableContent`](crate::TableContent) once it is known.
+    pub fn set_row_count(&self, row_count: usize) {
+        self.row_count_signal.set(Some(row_count));
+    }
+
+    /// Returns a `Signal` of the number of rows per page. This is `None` until it is set by [`Self::set_page_size`] or
+    /// taken from [`DisplayStrategy::Pagination`] by [`TableContent`](crate::TableContent).
+    pub fn page_size(&self) -> Signal<Option<usize>> {
+        self.page_size_signal.into()
+    }
+
+    /// Changes the number of rows per page. The current page is changed such that the first row that
+    /// was shown before is still shown.
+    pub fn set_page_size(&self, page_size: usize) {
+        let page_size = page_size.max(1);
+        let first_row = self.current_page.get_untracked()
+            * self.page_size_signal.get_untracked().unwrap_or(page_size);
+
+        self.page_size_signal.set(Some(page_size));
+        self.go_to(first_row / page_size);
+    }
+
+    /// Returns a `Signal` of the range of row indices that are shown on the current page, for example to
+    /// display "Showing 11–20 of 95". The end is limited by the number of rows if known.
+    pub fn page_row_range(&self) -> Signal<Range<usize>> {
+        let current_page = self.current_page;
+        let page_size = self.page_size_signal;
+        let row_count = self.row_count_signal;
+
+        Signal::derive(move || {
+            let page_size = page_size.get().unwrap_or_default();
+            let start = current_page.get() * page_size;
+            let end = start + page_size;
+
+            match row_count.get() {
+                Some(row_count) => start.min(row_count)..end.min(row_count),
+                None => start..end,
+            }
+        })
     }
 }
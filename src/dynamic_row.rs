@@ -0,0 +1,683 @@
+//! Rows whose columns are only known at runtime.
+
+use crate::cell_value::ExportNumber;
+use crate::components::renderer_fn::renderer_fn;
+use crate::events::impl_default_arc_fn;
+use crate::table_row::title_case;
+use crate::{
+    get_sorting_for_column, render_column_window, sort_rows_by, use_cell_selection_class,
+    use_cell_tabindex, ColumnSort, DefaultTableHeaderCellRenderer, DummyTableClassesProvider,
+    ExportValue, TableClassesProvider, TableDataProvider, TableHeadEvent, TableRow, TableRowText,
+};
+use leptos::prelude::*;
+use send_wrapper::SendWrapper;
+use std::borrow::Cow;
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fmt::{Display, Formatter};
+use std::ops::{Deref, Range};
+use std::sync::{Arc, Mutex, OnceLock};
+
+/// A single value of a [`DynamicRow`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum DynamicValue {
+    /// No value. Rendered as an empty cell.
+    #[default]
+    Null,
+    Bool(bool),
+    Int(i64),
+    Float(f64),
+    Text(String),
+}
+
+impl DynamicValue {
+    /// Returns `true` if this is [`DynamicValue::Null`].
+    pub fn is_null(&self) -> bool {
+        matches!(self, DynamicValue::Null)
+    }
+
+    /// A total order of values that is used for sorting. It's the same as [`PartialOrd`] except that
+    /// `NaN` is ordered after all other numbers and integers and floats are compared exactly.
+    ///
+    /// ```
+    /// # use leptos_struct_table::*;
+    /// # use std::cmp::Ordering;
+    /// let mut values = vec![
+    ///     DynamicValue::Float(f64::NAN),
+    ///     DynamicValue::Int(2),
+    ///     DynamicValue::Float(1.5),
+    ///     DynamicValue::Null,
+    /// ];
+    /// values.sort_by(DynamicValue::total_cmp);
+    ///
+    /// assert_eq!(values[0], DynamicValue::Null);
+    /// assert_eq!(values[1], DynamicValue::Float(1.5));
+    /// assert_eq!(values[2], DynamicValue::Int(2));
+    /// assert_eq!(values[1].total_cmp(&values[3]), Ordering::Less);
+    /// ```
+    pub fn total_cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (DynamicValue::Bool(a), DynamicValue::Bool(b)) => a.cmp(b),
+            (DynamicValue::Int(a), DynamicValue::Int(b)) => a.cmp(b),
+            (DynamicValue::Int(a), DynamicValue::Float(b)) => cmp_int_float(*a, *b),
+            (DynamicValue::Float(a), DynamicValue::Int(b)) => cmp_int_float(*b, *a).reverse(),
+            (DynamicValue::Float(a), DynamicValue::Float(b)) => cmp_float(*a, *b),
+            (DynamicValue::Text(a), DynamicValue::Text(b)) => a.cmp(b),
+            (a, b) => a.type_rank().cmp(&b.type_rank()),
+        }
+    }
+
+    fn type_rank(&self) -> u8 {
+        match self {
+            DynamicValue::Null => 0,
+            DynamicValue::Bool(_) => 1,
+            DynamicValue::Int(_) | DynamicValue::Float(_) => 2,
+            DynamicValue::Text(_) => 3,
+        }
+    }
+}
+
+impl Display for DynamicValue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DynamicValue::Null => Ok(()),
+            DynamicValue::Bool(value) => value.fmt(f),
+            DynamicValue::Int(value) => value.fmt(f),
+            DynamicValue::Float(value) => value.fmt(f),
+            DynamicValue::Text(value) => value.fmt(f),
+        }
+    }
+}
+
+/// Values of different kinds are ordered `Null < Bool < numbers < Text`.
+/// Integers and floats are compared numerically.
+impl PartialOrd for DynamicValue {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match (self, other) {
+            (DynamicValue::Bool(a), DynamicValue::Bool(b)) => a.partial_cmp(b),
+            (DynamicValue::Int(a), DynamicValue::Int(b)) => a.partial_cmp(b),
+            (DynamicValue::Int(a), DynamicValue::Float(b)) => (*a as f64).partial_cmp(b),
+            (DynamicValue::Float(a), DynamicValue::Int(b)) => a.partial_cmp(&(*b as f64)),
+            (DynamicValue::Float(a), DynamicValue::Float(b)) => a.partial_cmp(b),
+            (DynamicValue::Text(a), DynamicValue::Text(b)) => a.partial_cmp(b),
+            (a, b) => a.type_rank().partial_cmp(&b.type_rank()),
+        }
+    }
+}
+
+/// Orders `NaN` after all other numbers.
+fn cmp_float(a: f64, b: f64) -> Ordering {
+    a.partial_cmp(&b)
+        .unwrap_or_else(|| a.is_nan().cmp(&b.is_nan()))
+}
+
+/// Compares without converting the integer to `f64` which would round large values.
+fn cmp_int_float(a: i64, b: f64) -> Ordering {
+    // 2^63, the first value outside of the range of `i64`
+    const I64_END: f64 = 9_223_372_036_854_775_808.0;
+
+    if b.is_nan() || b >= I64_END {
+        return Ordering::Less;
+    }
+    if b < -I64_END {
+        return Ordering::Greater;
+    }
+
+    let trunc = b.trunc();
+    a.cmp(&(trunc as i64))
+        .then_with(|| trunc.partial_cmp(&b).unwrap_or(Ordering::Equal))
+}
+
+macro_rules! dynamic_value_from {
+    ($($variant:ident($($ty:ty),*)),* $(,)?) => {
+        $($(
+            impl From<$ty> for DynamicValue {
+                fn from(value: $ty) -> Self {
+                    DynamicValue::$variant(value.into())
+                }
+            }
+        )*)*
+    };
+}
+
+dynamic_value_from![
+    Bool(bool),
+    Int(i8, i16, i32, i64, u8, u16, u32),
+    Float(f32, f64),
+    Text(String, &str),
+];
+
+impl<T: Into<DynamicValue>> From<Option<T>> for DynamicValue {
+    fn from(value: Option<T>) -> Self {
+        value.map(Into::into).unwrap_or_default()
+    }
+}
+
+/// Interns owned column names so that [`TableRow::col_name`] can hand out `&'static str`s.
+/// Every distinct name is allocated only once, no matter how many column sets use it.
+fn intern(name: Cow<'static, str>) -> &'static str {
+    let name = match name {
+        Cow::Borrowed(name) => return name,
+        Cow::Owned(name) => name,
+    };
+
+    static NAMES: OnceLock<Mutex<HashSet<&'static str>>> = OnceLock::new();
+
+    let mut names = NAMES
+        .get_or_init(Default::default)
+        .lock()
+        .expect("not poisoned");
+
+    if let Some(name) = names.get(name.as_str()) {
+        return name;
+    }
+
+    let name: &'static str = Box::leak(name.into_boxed_str());
+    names.insert(name);
+    name
+}
+
+impl_default_arc_fn!(
+    /// New type wrapper of a closure that computes the value of a [`DynamicColumn`] from the values of a row.
+    DynamicAccessor<>(values: &HashMap<String, DynamicValue>) -> DynamicValue
+    { default DynamicValue::Null }
+);
+
+/// The default cell renderer of a [`DynamicColumn`]. Uses the `<td>` element.
+/// Please note that this is **NOT** a `#[component]`.
+#[allow(unused_variables)]
+pub fn DefaultDynamicCellRenderer<C>(
+    class: String,
+    value: Signal<DynamicValue>,
+    row: RwSignal<DynamicRow<C>>,
+    index: usize,
+) -> impl IntoView
+where
+    C: TableClassesProvider + Copy + Send + Sync + 'static,
+{
+    let selection_class = use_cell_selection_class(index);
+    let tabindex = use_cell_tabindex(index);
+
+    view! {
+        <td
+            class=move || format!("{class} {}", selection_class.read())
+            role="gridcell"
+            tabindex=tabindex
+        >
+            {move || value.read().to_string()}
+        </td>
+    }
+}
+
+renderer_fn!(
+    DynamicCellRendererFn<C>(
+        class: String,
+        value: Signal<DynamicValue>,
+        row: RwSignal<DynamicRow<C>>,
+        index: usize
+    )
+    default DefaultDynamicCellRenderer
+    where C: TableClassesProvider + Copy + Send + Sync + 'static
+);
+
+/// The runtime definition of a column of a [`DynamicRow`]. This is the counterpart of a
+/// struct field annotated with `#[table(...)]` attributes.
+///
+/// ```
+/// # use leptos_struct_table::*;
+/// let column = DynamicColumn::<TailwindClassesPreset>::new("first_name")
+///     .title("Name")
+///     .class("font-bold")
+///     .sortable(false);
+///
+/// assert_eq!(column.name(), "first_name");
+/// ```
+#[derive(Clone)]
+pub struct DynamicColumn<C = DummyTableClassesProvider>
+where
+    C: TableClassesProvider + Copy + Send + Sync + 'static,
+{
+    name: &'static str,
+    title: String,
+    class: String,
+    head_class: String,
+    cell_class: String,
+    sortable: bool,
+    accessor: Option<DynamicAccessor>,
+    renderer: DynamicCellRendererFn<C>,
+}
+
+impl<C> DynamicColumn<C>
+where
+    C: TableClassesProvider + Copy + Send + Sync + 'static,
+{
+    /// Creates a new column. `name` is the key used to look up the value in a [`DynamicRow`]
+    /// and is returned by [`TableRow::col_name`]. The title defaults to `name` in title case
+    /// (`this_field` becomes `"This Field"`) and the column is sortable.
+    ///
+    /// Prefer `&'static str` names. An owned `String` has to be kept alive for the rest of the program
+    /// because [`TableRow::col_name`] returns `&'static str`. It is allocated only once per distinct name.
+    pub fn new(name: impl Into<Cow<'static, str>>) -> Self {
+        let name = intern(name.into());
+
+        Self {
+            name,
+            title: title_case(name),
+            class: String::new(),
+            head_class: String::new(),
+            cell_class: String::new(),
+            sortable: true,
+            accessor: None,
+            renderer: DynamicCellRendererFn::default(),
+        }
+    }
+
+    /// The title that is displayed in the header cell.
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = title.into();
+        self
+    }
+
+    /// Classes that are applied to each cell (head and body) of this column.
+    pub fn class(mut self, class: impl Into<String>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// Classes that are applied to the header cell of this column.
+    pub fn head_class(mut self, class: impl Into<String>) -> Self {
+        self.head_class = class.into();
+        self
+    }
+
+    /// Classes that are applied to the body cells of this column.
+    pub fn cell_class(mut self, class: impl Into<String>) -> Self {
+        self.cell_class = class.into();
+        self
+    }
+
+    /// Whether clicking the header of this column changes the sorting. Defaults to `true`.
+    pub fn sortable(mut self, sortable: bool) -> Self {
+        self.sortable = sortable;
+        self
+    }
+
+    /// Computes the value of this column from the row instead of looking up `name` in the row's values.
+    /// This is the counterpart of the `getter` attribute.
+    pub fn accessor(mut self, accessor: impl Into<DynamicAccessor>) -> Self {
+        self.accessor = Some(accessor.into());
+        self
+    }
+
+    /// The cell renderer of this column. Defaults to [`DefaultDynamicCellRenderer`].
+    pub fn renderer(mut self, renderer: impl Into<DynamicCellRendererFn<C>>) -> Self {
+        self.renderer = renderer.into();
+        self
+    }
+
+    /// The name of this column.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// The value of this column in the given row.
+    pub fn value(&self, row: &DynamicRow<C>) -> DynamicValue {
+        match &self.accessor {
+            Some(accessor) => accessor.run(&row.values),
+            None => row.values.get(self.name).cloned().unwrap_or_default(),
+        }
+    }
+
+    /// Whether this column can be sorted.
+    pub fn is_sortable(&self) -> bool {
+        self.sortable
+    }
+
+    fn joined_class(&self, class: &str) -> String {
+        format!("{} {}", self.class, class).trim().to_string()
+    }
+}
+
+/// The list of columns shared by all [`DynamicRow`]s of a table.
+///
+/// The table head, [`TableRow::col_name`], [`TableRow::col_title`] and [`TableRow::column_count`] read the columns
+/// from the context, so you have to call `provide_context(columns.clone())` in the component that renders the
+/// [`TableContent`](crate::TableContent). Everything built on these functions like [`export_table`](crate::export_table),
+/// [`TableRow::sorting_to_sql`] or [`TableState`](crate::TableState) sees no columns without the context.
+/// Outside of a component, for example on the server, run the code with [`DynamicColumns::scope`].
+///
+/// ```
+/// # use leptos_struct_table::*;
+/// let columns = DynamicColumns::<DummyTableClassesProvider>::from(vec![DynamicColumn::new("name")]);
+///
+/// // without the columns in the context there are no columns
+/// assert_eq!(<DynamicRow>::column_count(), 0);
+/// assert_eq!(<DynamicRow>::col_name(0), "");
+///
+/// assert_eq!(columns.scope(<DynamicRow>::column_count), 1);
+/// assert_eq!(columns.scope(|| <DynamicRow>::col_name(0)), "name");
+/// ```
+#[derive(Clone)]
+pub struct DynamicColumns<C = DummyTableClassesProvider>(Arc<[DynamicColumn<C>]>)
+where
+    C: TableClassesProvider + Copy + Send + Sync + 'static;
+
+impl<C> Deref for DynamicColumns<C>
+where
+    C: TableClassesProvider + Copy + Send + Sync + 'static,
+{
+    type Target = [DynamicColumn<C>];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<C> FromIterator<DynamicColumn<C>> for DynamicColumns<C>
+where
+    C: TableClassesProvider + Copy + Send + Sync + 'static,
+{
+    fn from_iter<I: IntoIterator<Item = DynamicColumn<C>>>(iter: I) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+impl<C> From<Vec<DynamicColumn<C>>> for DynamicColumns<C>
+where
+    C: TableClassesProvider + Copy + Send + Sync + 'static,
+{
+    fn from(columns: Vec<DynamicColumn<C>>) -> Self {
+        Self(columns.into())
+    }
+}
+
+impl<C> Default for DynamicColumns<C>
+where
+    C: TableClassesProvider + Copy + Send + Sync + 'static,
+{
+    fn default() -> Self {
+        Self(Arc::new([]))
+    }
+}
+
+impl<C> DynamicColumns<C>
+where
+    C: TableClassesProvider + Copy + Send + Sync + 'static,
+{
+    /// The columns provided in the context or no columns if there are none.
+    fn from_context() -> Self {
+        use_context::<Self>().unwrap_or_default()
+    }
+
+    /// Runs `f` with these columns provided in the context of a new [`Owner`]. Use this to call the
+    /// column functions of [`TableRow`] outside of the component that renders the table.
+    ///
+    /// Futures only see the context while they are polled inside of the scope. Wrap them in
+    /// `ScopedFuture::new` to keep it:
+    ///
+    /// ```
+    /// # use leptos::reactive::computed::ScopedFuture;
+    /// # use leptos_struct_table::*;
+    /// # use std::collections::{HashMap, VecDeque};
+    /// let columns = DynamicColumns::<DummyTableClassesProvider>::from(vec![
+    ///     DynamicColumn::new("name"),
+    ///     DynamicColumn::new("age"),
+    /// ]);
+    /// let mut rows = columns.rows([HashMap::from([
+    ///     ("name".to_string(), DynamicValue::from("Ada")),
+    ///     ("age".to_string(), DynamicValue::from(36)),
+    /// ])]);
+    ///
+    /// let sorting = VecDeque::new();
+    ///
+    /// let export = columns.scope(|| {
+    ///     ScopedFuture::new(export_table(&mut rows, &sorting, CsvExporter::new(vec![])))
+    /// });
+    /// let csv = futures::executor::block_on(export).unwrap();
+    ///
+    /// assert_eq!(String::from_utf8(csv).unwrap(), "Name,Age\r\nAda,36\r\n");
+    /// ```
+    pub fn scope<T>(&self, f: impl FnOnce() -> T) -> T {
+        Owner::new().with(|| {
+            provide_context(self.clone());
+            f()
+        })
+    }
+
+    /// Creates a row with these columns from the given values.
+    pub fn row(&self, values: HashMap<String, DynamicValue>) -> DynamicRow<C> {
+        DynamicRow {
+            columns: self.clone(),
+            values,
+        }
+    }
+
+    /// Creates rows with these columns from the given values.
+    pub fn rows(
+        &self,
+        rows: impl IntoIterator<Item = HashMap<String, DynamicValue>>,
+    ) -> Vec<DynamicRow<C>> {
+        rows.into_iter().map(|values| self.row(values)).collect()
+    }
+
+    /// The index of the column with the given name.
+    pub fn position(&self, name: &str) -> Option<usize> {
+        self.iter().position(|column| column.name == name)
+    }
+}
+
+/// A row whose columns are defined at runtime by [`DynamicColumns`]. Use this
+/// instead of `#[derive(TableRow)]` when the columns come from the server (reports, ad-hoc
+/// queries, CSV uploads, ...).
+///
+/// `Vec<DynamicRow>` implements [`TableDataProvider`] including sorting. For other sources
+/// implement [`TableDataProvider<DynamicRow>`] yourself.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_struct_table::*;
+/// # use std::collections::HashMap;
+/// #[component]
+/// fn Report(data: Vec<HashMap<String, DynamicValue>>) -> impl IntoView {
+///     let columns = DynamicColumns::<TailwindClassesPreset>::from(vec![
+///         DynamicColumn::new("name"),
+///         DynamicColumn::new("revenue").title("Revenue (€)"),
+///     ]);
+///
+///     let rows = columns.rows(data);
+///     provide_context(columns);
+///
+///     view! {
+///         <table>
+///             <TableContent rows scroll_container="html" />
+///         </table>
+///     }
+/// }
+/// ```
+#[derive(Clone)]
+pub struct DynamicRow<C = DummyTableClassesProvider>
+where
+    C: TableClassesProvider + Copy + Send + Sync + 'static,
+{
+    columns: DynamicColumns<C>,
+    /// The values of this row by column name.
+    pub values: HashMap<String, DynamicValue>,
+}
+
+impl<C> DynamicRow<C>
+where
+    C: TableClassesProvider + Copy + Send + Sync + 'static,
+{
+    /// The columns of this row.
+    pub fn columns(&self) -> &DynamicColumns<C> {
+        &self.columns
+    }
+
+    /// The value of the column at the given index.
+    pub fn value(&self, col_index: usize) -> DynamicValue {
+        self.columns
+            .get(col_index)
+            .map(|column| column.value(self))
+            .unwrap_or_default()
+    }
+}
+
+impl<C> TableRow for DynamicRow<C>
+where
+    C: TableClassesProvider + Copy + Send + Sync + 'static,
+{
+    type ClassesProvider = C;
+
+    /// The actual count is provided by [`TableRow::column_count`].
+    const COLUMN_COUNT: usize = 0;
+
+    fn render_row(row: RwSignal<Self>, _index: usize) -> impl IntoView {
+        let class_provider = C::new();
+        let columns = row.read_untracked().columns.clone();
+
+        render_column_window(columns.len(), move |col_index| {
+            let column = columns[col_index].clone();
+            let value = Signal::derive({
+                let column = column.clone();
+                move || column.value(&row.read())
+            });
+
+            column.renderer.run(
+                class_provider.cell(&column.joined_class(&column.cell_class)),
+                value,
+                row,
+                col_index,
+            )
+        })
+    }
+
+    fn render_head_row<F>(
+        sorting: Signal<VecDeque<(usize, ColumnSort)>>,
+        on_head_click: F,
+    ) -> impl IntoView
+    where
+        F: Fn(TableHeadEvent) + Clone + 'static,
+    {
+        let class_provider = C::new();
+        let columns = DynamicColumns::<C>::from_context();
+        // the header cells are only ever rendered in the browser
+        let on_head_click = SendWrapper::new(on_head_click);
+
+        render_column_window(columns.len(), move |index| {
+            let column = &columns[index];
+            let head_class = column.joined_class(&column.head_class);
+            let title = column.title.clone();
+            let sortable = column.sortable;
+            let on_head_click = (*on_head_click).clone();
+
+            view! {
+                <DefaultTableHeaderCellRenderer
+                    class=Signal::derive(move || {
+                        class_provider
+                            .thead_cell(get_sorting_for_column(index, sorting), &head_class)
+                    })
+                    inner_class=class_provider.thead_cell_inner()
+                    index=index
+                    sort_priority=Signal::derive(move || {
+                        let sorting = sorting.read();
+                        if sorting.len() < 2 {
+                            return None;
+                        }
+                        sorting.iter().position(|(col, _)| *col == index)
+                    })
+                    sort_direction=Signal::derive(move || get_sorting_for_column(index, sorting))
+                    on_click=move |event| {
+                        if sortable {
+                            on_head_click(event);
+                        }
+                    }
+                >
+                    {title}
+                </DefaultTableHeaderCellRenderer>
+            }
+        })
+    }
+
+    /// Reads the columns from the context. See [`DynamicColumns`].
+    /// Returns an empty name if there is no such column.
+    fn col_name(col_index: usize) -> &'static str {
+        DynamicColumns::<C>::from_context()
+            .get(col_index)
+            .map(|column| column.name)
+            .unwrap_or_default()
+    }
+
+    /// Reads the columns from the context. See [`DynamicColumns`].
+    /// Returns `0` if there are none.
+    fn column_count() -> usize {
+        DynamicColumns::<C>::from_context().len()
+    }
+
+    /// Reads the columns from the context. See [`DynamicColumns`].
+    /// Returns an empty title if there is no such column.
+    fn col_title(col_index: usize) -> String {
+        DynamicColumns::<C>::from_context()
+            .get(col_index)
+            .map(|column| column.title.clone())
+            .unwrap_or_default()
+    }
+}
+
+impl<C> TableRowText for DynamicRow<C>
+where
+    C: TableClassesProvider + Copy + Send + Sync + 'static,
+{
+    fn cell_text(&self, col_index: usize) -> String {
+        self.value(col_index).to_string()
+    }
+
+    fn export_value(&self, col_index: usize) -> ExportValue {
+        match self.value(col_index) {
+            DynamicValue::Null => ExportValue::Empty,
+            DynamicValue::Bool(value) => ExportValue::Bool(value),
+            DynamicValue::Int(value) => value.export_number(None),
+            DynamicValue::Float(value) => ExportValue::Number {
+                value,
+                precision: None,
+            },
+            DynamicValue::Text(value) => ExportValue::Text(value),
+        }
+    }
+}
+
+impl<C> TableDataProvider<DynamicRow<C>> for Vec<DynamicRow<C>>
+where
+    C: TableClassesProvider + Copy + Send + Sync + 'static,
+{
+    async fn get_rows(
+        &self,
+        range: Range<usize>,
+    ) -> Result<(Vec<DynamicRow<C>>, Range<usize>), String> {
+        Ok(crate::get_vec_range_clamped(self, range))
+    }
+
+    async fn row_count(&self) -> Option<usize> {
+        Some(self.len())
+    }
+
+    fn set_sorting(&mut self, sorting: &VecDeque<(usize, ColumnSort)>) {
+        let Some(columns) = self.first().map(|row| row.columns.clone()) else {
+            return;
+        };
+
+        let sorting = sorting
+            .iter()
+            .filter(|(col_index, _)| {
+                columns
+                    .get(*col_index)
+                    .is_some_and(|column| column.sortable)
+            })
+            .copied()
+            .collect();
+
+        sort_rows_by(self, &sorting, |a, b, col_index| {
+            let column = &columns[col_index];
+            column.value(a).total_cmp(&column.value(b))
+        });
+    }
+}
@@ -0,0 +1,183 @@
+use crate::{ExportError, TableExporter, TableRowText};
+use std::io::Write;
+
+/// When to put fields in quotes.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_struct_table::*;
+/// #
+/// #[derive(TableRow, Clone)]
+/// #[table(impl_row_text)]
+/// struct Book {
+///     title: String,
+///     pages: u32,
+/// }
+///
+/// fn export(quote_style: CsvQuoteStyle) -> String {
+///     let mut exporter = CsvExporter::new(vec![]).quote_style(quote_style).header(false);
+///     TableExporter::<Book>::write_header(&mut exporter, &["Title".to_string(), "Pages".to_string()]).unwrap();
+///     exporter.write_row(&Book { title: "Dune".to_string(), pages: 412 }).unwrap();
+///     exporter.write_row(&Book { title: "Hello, \"World\"".to_string(), pages: 1 }).unwrap();
+///
+///     String::from_utf8(TableExporter::<Book>::finish(exporter).unwrap()).unwrap()
+/// }
+///
+/// assert_eq!(export(CsvQuoteStyle::Necessary), "Dune,412\r\n\"Hello, \"\"World\"\"\",1\r\n");
+/// assert_eq!(export(CsvQuoteStyle::Always), "\"Dune\",\"412\"\r\n\"Hello, \"\"World\"\"\",\"1\"\r\n");
+/// assert_eq!(export(CsvQuoteStyle::Never), "Dune,412\r\nHello, \"World\",1\r\n");
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum CsvQuoteStyle {
+    /// Only quote fields that contain the delimiter, a quote or a line break (the default).
+    #[default]
+    Necessary,
+    /// Quote every field.
+    Always,
+    /// Never quote fields. The output might not be parsable if fields contain special characters.
+    Never,
+}
+
+/// Exports a table as CSV according to RFC 4180. Use it with [`export_table`](crate::export_table).
+///
+/// The rows are streamed into `writer` which can be anything that implements [`Write`].
+/// The cells are rendered with [`TableRowText::cell_text`].
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_struct_table::*;
+/// #
+/// #[derive(TableRow, Clone)]
+/// #[table(impl_row_text)]
+/// struct Quote {
+///     author: String,
+///     text: String,
+/// }
+///
+/// let mut exporter = CsvExporter::new(vec![]).delimiter(b';').bom(true);
+///
+/// TableExporter::<Quote>::write_header(&mut exporter, &["Author".to_string(), "Text".to_string()]).unwrap();
+/// exporter.write_row(&Quote {
+///     author: "Kant".to_string(),
+///     text: "Sapere aude; dare to know".to_string(),
+/// }).unwrap();
+///
+/// let csv = TableExporter::<Quote>::finish(exporter).unwrap();
+/// assert_eq!(
+///     String::from_utf8(csv).unwrap(),
+///     "\u{feff}Author;Text\r\nKant;\"Sapere aude; dare to know\"\r\n"
+/// );
+/// ```
+pub struct CsvExporter<W: Write> {
+    writer: W,
+    delimiter: u8,
+    quote_style: CsvQuoteStyle,
+    bom: bool,
+    header: bool,
+    column_count: usize,
+}
+
+impl<W: Write> CsvExporter<W> {
+    /// Creates a new exporter that writes to `writer`. By default the delimiter is `,`,
+    /// fields are only quoted if necessary, no BOM is written and the header row is included.
+    pub fn new(writer: W) -> Self {
+        Self {
+            writer,
+            delimiter: b',',
+            quote_style: CsvQuoteStyle::default(),
+            bom: false,
+            header: true,
+            column_count: 0,
+        }
+    }
+
+    /// The field delimiter. Defaults to `b','`.
+    pub fn delimiter(mut self, delimiter: u8) -> Self {
+        self.delimiter = delimiter;
+        self
+    }
+
+    /// When to put fields in quotes. Defaults to [`CsvQuoteStyle::Necessary`].
+    pub fn quote_style(mut self, quote_style: CsvQuoteStyle) -> Self {
+        self.quote_style = quote_style;
+        self
+    }
+
+    /// Write a UTF-8 byte order mark at the start. This makes Excel detect the encoding correctly.
+    /// Defaults to `false`.
+    pub fn bom(mut self, bom: bool) -> Self {
+        self.bom = bom;
+        self
+    }
+
+    /// Write the column titles as the first row. Defaults to `true`.
+    pub fn header(mut self, header: bool) -> Self {
+        self.header = header;
+        self
+    }
+
+    fn write_record<'a>(
+        &mut self,
+        fields: impl IntoIterator<Item = &'a str>,
+    ) -> Result<(), ExportError> {
+        for (i, field) in fields.into_iter().enumerate() {
+            if i > 0 {
+                self.writer.write_all(&[self.delimiter])?;
+            }
+
+            let quote = match self.quote_style {
+                CsvQuoteStyle::Always => true,
+                CsvQuoteStyle::Never => false,
+                CsvQuoteStyle::Necessary => field
+                    .bytes()
+                    .any(|b| b == self.delimiter || matches!(b, b'"' | b'\n' | b'\r')),
+            };
+
+            if quote {
+                write!(self.writer, "\"{}\"", field.replace('"', "\"\""))?;
+            } else {
+                self.writer.write_all(field.as_bytes())?;
+            }
+        }
+
+        self.writer.write_all(b"\r\n")?;
+
+        Ok(())
+    }
+}
+
+impl<Row, W> TableExporter<Row> for CsvExporter<W>
+where
+    Row: TableRowText,
+    W: Write,
+{
+    type Output = W;
+
+    fn write_header(&mut self, titles: &[String]) -> Result<(), ExportError> {
+        self.column_count = titles.len();
+
+        if self.bom {
+            self.writer.write_all("\u{feff}".as_bytes())?;
+        }
+
+        if self.header {
+            self.write_record(titles.iter().map(String::as_str))?;
+        }
+
+        Ok(())
+    }
+
+    fn write_row(&mut self, row: &Row) -> Result<(), ExportError> {
+        let cells = (0..self.column_count)
+            .map(|col_index| row.cell_text(col_index))
+            .collect::<Vec<_>>();
+
+        self.write_record(cells.iter().map(String::as_str))
+    }
+
+    fn finish(mut self) -> Result<Self::Output, ExportError> {
+        self.writer.flush()?;
+
+        Ok(self.writer)
+    }
+}
@@ -0,0 +1,227 @@
+mod csv;
+#[cfg(feature = "xlsx")]
+mod xlsx;
+
+use crate::{ColumnSort, TableDataProvider, TableRowText};
+pub use csv::*;
+use std::collections::VecDeque;
+use std::fmt::Debug;
+use wasm_bindgen::JsCast;
+#[cfg(feature = "xlsx")]
+pub use xlsx::*;
+
+/// Number of rows that are requested at once by [`export_table`] if the data provider
+/// doesn't specify a [`TableDataProvider::CHUNK_SIZE`].
+pub const DEFAULT_EXPORT_CHUNK_SIZE: usize = 1000;
+
+/// A typed cell value used by exporters that support more than plain text like the `XlsxExporter`.
+///
+/// Produced by [`TableRowText::export_value`] and [`CellText::export_value`](crate::CellText::export_value).
+#[derive(Clone, Debug, PartialEq)]
+pub enum ExportValue {
+    /// An empty cell.
+    Empty,
+    Text(String),
+    Bool(bool),
+    /// A number. If `precision` is given, it is displayed with that many digits after the decimal point.
+    Number {
+        value: f64,
+        precision: Option<usize>,
+    },
+    /// A decimal number in its exact textual representation like `"1234.5600"`. Exporters that can't store
+    /// it as a number without losing precision write the text instead. `precision` is the same as for [`ExportValue::Number`].
+    ///
+    /// Integers that don't fit exactly into an `f64` are exported like this as well.
+    ///
+    /// ```
+    /// # use leptos_struct_table::*;
+    /// let options = NumberRenderOptions::default();
+    ///
+    /// assert_eq!(
+    ///     42_i64.export_value(&options),
+    ///     ExportValue::Number { value: 42.0, precision: None }
+    /// );
+    /// assert_eq!(
+    ///     u64::MAX.export_value(&options),
+    ///     ExportValue::Decimal { value: "18446744073709551615".to_string(), precision: None }
+    /// );
+    /// ```
+    Decimal {
+        value: String,
+        precision: Option<usize>,
+    },
+    Date {
+        year: i32,
+        month: u8,
+        day: u8,
+    },
+    Time {
+        hour: u8,
+        minute: u8,
+        second: u8,
+        nanosecond: u32,
+    },
+    DateTime {
+        year: i32,
+        month: u8,
+        day: u8,
+        hour: u8,
+        minute: u8,
+        second: u8,
+        nanosecond: u32,
+    },
+}
+
+/// Error that can occur while exporting a table.
+#[derive(Debug, thiserror::Error)]
+pub enum ExportError {
+    /// [`TableDataProvider::get_rows`] returned an error.
+    #[error("failed to load rows: {0}")]
+    DataProvider(String),
+
+    /// Writing the output failed.
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+
+    /// Writing the Excel workbook failed.
+    #[cfg(feature = "xlsx")]
+    #[error(transparent)]
+    Xlsx(#[from] rust_xlsxwriter::XlsxError),
+}
+
+/// A format that a table can be exported to. The rows are streamed into the exporter
+/// by [`export_table`] in the current sort order.
+///
+/// See [`CsvExporter`] for an implementation. With the feature `xlsx` there is also `XlsxExporter`.
+pub trait TableExporter<Row> {
+    /// The result of the export, e.g. the bytes of the written file.
+    type Output;
+
+    /// Called once before any row with the titles of all columns.
+    fn write_header(&mut self, titles: &[String]) -> Result<(), ExportError>;
+
+    /// Called once for every row in the order of the table.
+    fn write_row(&mut self, row: &Row) -> Result<(), ExportError>;
+
+    /// Called after the last row to produce the output.
+    fn finish(self) -> Result<Self::Output, ExportError>;
+}
+
+/// Exports all rows of the data provider with the given sorting applied.
+///
+/// The rows are loaded in steps of [`TableDataProvider::CHUNK_SIZE`] (or [`DEFAULT_EXPORT_CHUNK_SIZE`] if
+/// not specified) until the end of the data is reached. Any filters you have implemented in your data provider
+/// apply to the export just like they do to the table.
+///
+/// The column titles are taken from [`TableRow::col_title`](crate::TableRow::col_title). If you export [`DynamicRow`](crate::DynamicRow)s
+/// the columns have to be in the context. See [`DynamicColumns::scope`](crate::DynamicColumns::scope).
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_struct_table::*;
+/// # use std::collections::VecDeque;
+/// #
+/// #[derive(TableRow, Clone)]
+/// #[table(sortable, impl_vec_data_provider, impl_row_text)]
+/// pub struct Book {
+///     title: String,
+///     #[table(title = "Written by")]
+///     author: String,
+///     #[table(format(precision = 2usize))]
+///     price: f64,
+/// }
+///
+/// async fn export_books(mut books: Vec<Book>, sorting: VecDeque<(usize, ColumnSort)>) -> Vec<u8> {
+///     export_table(&mut books, &sorting, CsvExporter::new(vec![]))
+///         .await
+///         .expect("writing to a Vec never fails")
+/// }
+///
+/// let books = vec![
+///     Book { title: "Dune".to_string(), author: "Frank Herbert".to_string(), price: 9.5 },
+///     Book { title: "Emma".to_string(), author: "Jane Austen".to_string(), price: 12.0 },
+/// ];
+/// let sorting = VecDeque::from([(2, ColumnSort::Descending)]);
+///
+/// let csv = futures::executor::block_on(export_books(books, sorting));
+/// assert_eq!(
+///     String::from_utf8(csv).unwrap(),
+///     "Title,Written by,Price\r\nEmma,Jane Austen,12.00\r\nDune,Frank Herbert,9.50\r\n"
+/// );
+///
+/// // in the browser let the user save the file
+/// # fn download(csv: Vec<u8>) {
+/// download_file(&csv, "books.csv", "text/csv");
+/// # }
+/// ```
+pub async fn export_table<Row, Err, DataP, E>(
+    rows: &mut DataP,
+    sorting: &VecDeque<(usize, ColumnSort)>,
+    mut exporter: E,
+) -> Result<E::Output, ExportError>
+where
+    Row: TableRowText,
+    DataP: TableDataProvider<Row, Err>,
+    Err: Debug,
+    E: TableExporter<Row>,
+{
+    rows.set_sorting(sorting);
+
+    let titles = (0..Row::column_count())
+        .map(Row::col_title)
+        .collect::<Vec<_>>();
+    exporter.write_header(&titles)?;
+
+    let chunk_size = DataP::CHUNK_SIZE.unwrap_or(DEFAULT_EXPORT_CHUNK_SIZE);
+    let row_count = rows.row_count().await;
+
+    let mut start = 0;
+    while row_count.is_none_or(|row_count| start < row_count) {
+        let (loaded_rows, loaded_range) = rows
+            .get_rows(start..start + chunk_size)
+            .await
+            .map_err(|err| ExportError::DataProvider(format!("{err:?}")))?;
+
+        // the provider may return more than requested but never rows we already exported
+        let skip = start.saturating_sub(loaded_range.start);
+        for row in loaded_rows.iter().skip(skip) {
+            exporter.write_row(row)?;
+        }
+
+        // an empty batch means there are no more rows even if the row count says otherwise
+        if loaded_rows.is_empty() || loaded_range.end < start + chunk_size {
+            break;
+        }
+        start = loaded_range.end;
+    }
+
+    exporter.finish()
+}
+
+/// Lets the browser download the given bytes as a file with the given name and MIME type.
+pub fn download_file(bytes: &[u8], file_name: &str, mime_type: &str) {
+    let parts = js_sys::Array::of1(&js_sys::Uint8Array::from(bytes));
+
+    let options = web_sys::BlobPropertyBag::new();
+    options.set_type(mime_type);
+
+    let blob = web_sys::Blob::new_with_u8_array_sequence_and_options(&parts, &options)
+        .expect("Blob creation failed");
+    let url = web_sys::Url::create_object_url_with_blob(&blob).expect("Object URL creation failed");
+
+    let anchor = leptos::prelude::document()
+        .create_element("a")
+        .expect("Element creation failed")
+        .unchecked_into::<web_sys::HtmlAnchorElement>();
+    anchor.set_href(&url);
+    anchor.set_download(file_name);
+    anchor.click();
+
+    // revoking the URL right away cancels the download in some browsers
+    leptos::prelude::set_timeout(
+        move || {
+            let _ = web_sys::Url::revoke_object_url(&url);
+        },
+        std::time::Duration::from_secs(1),
+    );
+}
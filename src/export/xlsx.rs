@@ -0,0 +1,269 @@
+use crate::{ExportError, ExportValue, TableExporter, TableRowText};
+use rust_xlsxwriter::{Color, ExcelDateTime, Format, FormatBorder, Workbook, Worksheet};
+use std::collections::HashMap;
+
+/// Exports a table as an Excel workbook with a single worksheet. Use it with [`export_table`](crate::export_table).
+///
+/// The cells are written with their types from [`TableRowText::export_value`]: numbers stay numbers,
+/// dates and times become Excel dates and numbers with a precision keep their number of decimal places.
+/// Decimals and integers with more significant digits than Excel can store are written as text to keep them exact.
+/// The header row is styled and frozen by default and the column widths are fit to the content
+/// unless given explicitly. The output are the bytes of the `.xlsx` file.
+///
+/// ```
+/// # use leptos_struct_table::*;
+/// let exporter = XlsxExporter::new()
+///     .sheet_name("Books")
+///     .column_widths(vec![40.0, 20.0])
+///     .date_format("dd.mm.yyyy");
+/// ```
+pub struct XlsxExporter {
+    worksheet: Worksheet,
+    sheet_name: Option<String>,
+    header_format: Format,
+    freeze_header: bool,
+    column_widths: Option<Vec<f64>>,
+    date_format: Format,
+    time_format: Format,
+    date_time_format: Format,
+    number_formats: HashMap<usize, Format>,
+    column_count: usize,
+    row: u32,
+}
+
+impl Default for XlsxExporter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl XlsxExporter {
+    /// Creates a new exporter with a bold, shaded and frozen header row, fitted column widths and
+    /// ISO 8601 date formats.
+    pub fn new() -> Self {
+        Self {
+            worksheet: Worksheet::new(),
+            sheet_name: None,
+            header_format: Format::new()
+                .set_bold()
+                .set_background_color(Color::RGB(0xE5E7EB))
+                .set_border_bottom(FormatBorder::Thin),
+            freeze_header: true,
+            column_widths: None,
+            date_format: Format::new().set_num_format("yyyy-mm-dd"),
+            time_format: Format::new().set_num_format("hh:mm:ss"),
+            date_time_format: Format::new().set_num_format("yyyy-mm-dd hh:mm:ss"),
+            number_formats: HashMap::new(),
+            column_count: 0,
+            row: 0,
+        }
+    }
+
+    /// The name of the worksheet. Defaults to `"Sheet1"`.
+    pub fn sheet_name(mut self, sheet_name: impl Into<String>) -> Self {
+        self.sheet_name = Some(sheet_name.into());
+        self
+    }
+
+    /// The format of the header cells.
+    pub fn header_format(mut self, header_format: Format) -> Self {
+        self.header_format = header_format;
+        self
+    }
+
+    /// Keep the header row visible when scrolling. Defaults to `true`.
+    pub fn freeze_header(mut self, freeze_header: bool) -> Self {
+        self.freeze_header = freeze_header;
+        self
+    }
+
+    /// The widths of the columns in Excel character units. By default the widths are fit to the content.
+    pub fn column_widths(mut self, column_widths: Vec<f64>) -> Self {
+        self.column_widths = Some(column_widths);
+        self
+    }
+
+    /// The Excel number format for dates. Defaults to `"yyyy-mm-dd"`.
+    pub fn date_format(mut self, format: impl Into<String>) -> Self {
+        self.date_format = Format::new().set_num_format(format);
+        self
+    }
+
+    /// The Excel number format for times. Defaults to `"hh:mm:ss"`.
+    pub fn time_format(mut self, format: impl Into<String>) -> Self {
+        self.time_format = Format::new().set_num_format(format);
+        self
+    }
+
+    /// The Excel number format for date times. Defaults to `"yyyy-mm-dd hh:mm:ss"`.
+    pub fn date_time_format(mut self, format: impl Into<String>) -> Self {
+        self.date_time_format = Format::new().set_num_format(format);
+        self
+    }
+
+    fn write_value(&mut self, col: u16, value: ExportValue) -> Result<(), ExportError> {
+        let row = self.row;
+
+        match value {
+            ExportValue::Empty => {}
+            ExportValue::Text(text) => {
+                self.worksheet.write_string(row, col, text)?;
+            }
+            ExportValue::Bool(value) => {
+                self.worksheet.write_boolean(row, col, value)?;
+            }
+            ExportValue::Number {
+                value,
+                precision: None,
+            } => {
+                self.worksheet.write_number(row, col, value)?;
+            }
+            ExportValue::Number {
+                value,
+                precision: Some(precision),
+            } => {
+                let format = self.number_formats.entry(precision).or_insert_with(|| {
+                    let decimals = "0".repeat(precision);
+                    Format::new().set_num_format(if precision == 0 {
+                        "0".to_string()
+                    } else {
+                        format!("0.{decimals}")
+                    })
+                });
+                self.worksheet
+                    .write_number_with_format(row, col, value, format)?;
+            }
+            ExportValue::Decimal { value, precision } => match value.parse::<f64>() {
+                Ok(number) if number.is_finite() && significant_digits(&value) <= EXCEL_DIGITS => {
+                    self.write_value(
+                        col,
+                        ExportValue::Number {
+                            value: number,
+                            precision,
+                        },
+                    )?;
+                }
+                _ => {
+                    self.worksheet.write_string(row, col, value)?;
+                }
+            },
+            ExportValue::Date { year, month, day } => {
+                let date = excel_date(year, month, day)?;
+                self.worksheet
+                    .write_datetime_with_format(row, col, date, &self.date_format)?;
+            }
+            ExportValue::Time {
+                hour,
+                minute,
+                second,
+                nanosecond,
+            } => {
+                let time = ExcelDateTime::from_hms(
+                    hour.into(),
+                    minute,
+                    second as f64 + nanosecond as f64 / 1e9,
+                )?;
+                self.worksheet
+                    .write_datetime_with_format(row, col, time, &self.time_format)?;
+            }
+            ExportValue::DateTime {
+                year,
+                month,
+                day,
+                hour,
+                minute,
+                second,
+                nanosecond,
+            } => {
+                let date_time = excel_date(year, month, day)?.and_hms(
+                    hour.into(),
+                    minute,
+                    second as f64 + nanosecond as f64 / 1e9,
+                )?;
+                self.worksheet.write_datetime_with_format(
+                    row,
+                    col,
+                    date_time,
+                    &self.date_time_format,
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Excel keeps 15 significant digits of a number.
+const EXCEL_DIGITS: usize = 15;
+
+fn significant_digits(decimal: &str) -> usize {
+    let digits = decimal
+        .chars()
+        .filter(char::is_ascii_digit)
+        .collect::<String>();
+
+    digits.trim_start_matches('0').trim_end_matches('0').len()
+}
+
+fn excel_date(year: i32, month: u8, day: u8) -> Result<ExcelDateTime, ExportError> {
+    let year = u16::try_from(year)
+        .map_err(|_| rust_xlsxwriter::XlsxError::DateTimeRangeError(year.to_string()))?;
+
+    Ok(ExcelDateTime::from_ymd(year, month, day)?)
+}
+
+impl<Row> TableExporter<Row> for XlsxExporter
+where
+    Row: TableRowText,
+{
+    type Output = Vec<u8>;
+
+    fn write_header(&mut self, titles: &[String]) -> Result<(), ExportError> {
+        self.column_count = titles.len();
+
+        for (col, title) in titles.iter().enumerate() {
+            self.worksheet
+                .write_string_with_format(0, col as u16, title, &self.header_format)?;
+        }
+
+        if self.freeze_header {
+            self.worksheet.set_freeze_panes(1, 0)?;
+        }
+
+        self.row = 1;
+
+        Ok(())
+    }
+
+    fn write_row(&mut self, row: &Row) -> Result<(), ExportError> {
+        for col_index in 0..self.column_count {
+            self.write_value(col_index as u16, row.export_value(col_index))?;
+        }
+
+        self.row += 1;
+
+        Ok(())
+    }
+
+    fn finish(mut self) -> Result<Self::Output, ExportError> {
+        match self.column_widths.take() {
+            Some(column_widths) => {
+                for (col, width) in column_widths.into_iter().enumerate() {
+                    self.worksheet.set_column_width(col as u16, width)?;
+                }
+            }
+            None => {
+                self.worksheet.autofit();
+            }
+        }
+
+        if let Some(sheet_name) = &self.sheet_name {
+            self.worksheet.set_name(sheet_name)?;
+        }
+
+        let mut workbook = Workbook::new();
+        workbook.push_worksheet(self.worksheet);
+
+        Ok(workbook.save_to_buffer()?)
+    }
+}
@@ -0,0 +1,228 @@
+use crate::{CellPosition, RowIndex};
+use leptos::prelude::*;
+use std::collections::HashMap;
+
+/// An action that is triggered by a key press inside the table. See [`KeyBindings`].
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum TableAction {
+    /// Move the focused cell one row up.
+    MoveUp,
+    /// Move the focused cell one row down.
+    MoveDown,
+    /// Move the focused cell one column to the left.
+    MoveLeft,
+    /// Move the focused cell one column to the right.
+    MoveRight,
+    /// Move the focused cell to the first column of its row.
+    MoveToRowStart,
+    /// Move the focused cell to the last column of its row.
+    MoveToRowEnd,
+    /// Move the focused cell up by the number of visible rows.
+    MovePageUp,
+    /// Move the focused cell down by the number of visible rows.
+    MovePageDown,
+    /// Move the focused cell to the first cell of the table.
+    MoveToFirstCell,
+    /// Move the focused cell to the last cell of the table.
+    MoveToLastCell,
+    /// Move the focused cell one row up and extend the [`Selection::Cells`](crate::Selection::Cells) to it.
+    ExtendUp,
+    /// Move the focused cell one row down and extend the [`Selection::Cells`](crate::Selection::Cells) to it.
+    ExtendDown,
+    /// Move the focused cell one column to the left and extend the [`Selection::Cells`](crate::Selection::Cells) to it.
+    ExtendLeft,
+    /// Move the focused cell one column to the right and extend the [`Selection::Cells`](crate::Selection::Cells) to it.
+    ExtendRight,
+    /// Select the focused row or cell like a click would.
+    Select,
+}
+
+/// A key together with its modifiers as used by [`KeyBindings`].
+///
+/// The key is the value of [`KeyboardEvent.key`](https://developer.mozilla.org/en-US/docs/Web/API/KeyboardEvent/key)
+/// like `"ArrowUp"`, `"Enter"` or `"a"`. `ctrl` matches the Ctrl key as well as the Cmd key on macOS.
+///
+/// Key bindings can be parsed from strings like `"Ctrl+Shift+Home"`. `"Space"` stands for the space bar.
+///
+/// ```
+/// # use leptos_struct_table::*;
+/// let binding = KeyBinding::from("Ctrl+Home");
+///
+/// assert_eq!(binding, KeyBinding::new("Home").ctrl(true));
+/// assert_eq!(KeyBinding::from("Space"), KeyBinding::new(" "));
+/// ```
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct KeyBinding {
+    pub key: String,
+    pub ctrl: bool,
+    pub shift: bool,
+    pub alt: bool,
+}
+
+impl KeyBinding {
+    /// A binding of the given key without modifiers.
+    pub fn new(key: impl Into<String>) -> Self {
+        let key = key.into();
+
+        Self {
+            key: normalize_key(&key),
+            ctrl: false,
+            shift: false,
+            alt: false,
+        }
+    }
+
+    /// Require the Ctrl (or Cmd) key to be pressed.
+    pub fn ctrl(mut self, ctrl: bool) -> Self {
+        self.ctrl = ctrl;
+        self
+    }
+
+    /// Require the Shift key to be pressed.
+    pub fn shift(mut self, shift: bool) -> Self {
+        self.shift = shift;
+        self
+    }
+
+    /// Require the Alt (or Option) key to be pressed.
+    pub fn alt(mut self, alt: bool) -> Self {
+        self.alt = alt;
+        self
+    }
+
+    /// The binding that matches the given keyboard event.
+    pub fn from_event(event: &web_sys::KeyboardEvent) -> Self {
+        Self::new(event.key())
+            .ctrl(event.ctrl_key() || event.meta_key())
+            .shift(event.shift_key())
+            .alt(event.alt_key())
+    }
+}
+
+fn normalize_key(key: &str) -> String {
+    match key {
+        "Space" | "Spacebar" => " ".to_string(),
+        key if key.chars().count() == 1 => key.to_lowercase(),
+        key => key.to_string(),
+    }
+}
+
+impl From<&str> for KeyBinding {
+    fn from(binding: &str) -> Self {
+        let mut parts = binding.split('+').collect::<Vec<_>>();
+        // a binding of the plus key itself ends with an empty part
+        let key = match parts.pop() {
+            Some("") => "+",
+            Some(key) => key,
+            None => "",
+        };
+
+        parts
+            .iter()
+            .fold(Self::new(key.trim()), |binding, modifier| {
+                match modifier.trim().to_lowercase().as_str() {
+                    "ctrl" | "control" | "cmd" | "meta" => binding.ctrl(true),
+                    "shift" => binding.shift(true),
+                    "alt" | "option" => binding.alt(true),
+                    _ => binding,
+                }
+            })
+    }
+}
+
+/// Maps keys to the [`TableAction`]s of the keyboard navigation of [`TableContent`](crate::TableContent).
+///
+/// The default bindings are
+/// - Arrow keys: move the focused cell
+/// - Home / End: move to the first / last cell in the row
+/// - PageUp / PageDown: move by the number of visible rows
+/// - Ctrl+Home / Ctrl+End: move to the first / last cell of the table
+/// - Shift+Arrow keys: extend the cell selection
+/// - Space / Enter: select the focused row or cell
+///
+/// ```
+/// # use leptos_struct_table::*;
+/// let key_bindings = KeyBindings::default()
+///     .bind("k", TableAction::MoveUp)
+///     .bind("j", TableAction::MoveDown)
+///     .unbind("Enter");
+///
+/// assert_eq!(key_bindings.action(&KeyBinding::new("j")), Some(TableAction::MoveDown));
+/// assert_eq!(key_bindings.action(&KeyBinding::new("Enter")), None);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct KeyBindings(HashMap<KeyBinding, TableAction>);
+
+impl Default for KeyBindings {
+    fn default() -> Self {
+        Self::new()
+            .bind("ArrowUp", TableAction::MoveUp)
+            .bind("ArrowDown", TableAction::MoveDown)
+            .bind("ArrowLeft", TableAction::MoveLeft)
+            .bind("ArrowRight", TableAction::MoveRight)
+            .bind("Home", TableAction::MoveToRowStart)
+            .bind("End", TableAction::MoveToRowEnd)
+            .bind("PageUp", TableAction::MovePageUp)
+            .bind("PageDown", TableAction::MovePageDown)
+            .bind("Ctrl+Home", TableAction::MoveToFirstCell)
+            .bind("Ctrl+End", TableAction::MoveToLastCell)
+            .bind("Shift+ArrowUp", TableAction::ExtendUp)
+            .bind("Shift+ArrowDown", TableAction::ExtendDown)
+            .bind("Shift+ArrowLeft", TableAction::ExtendLeft)
+            .bind("Shift+ArrowRight", TableAction::ExtendRight)
+            .bind("Space", TableAction::Select)
+            .bind("Enter", TableAction::Select)
+    }
+}
+
+impl KeyBindings {
+    /// Key bindings without any binding. Use this to disable the keyboard navigation.
+    pub fn new() -> Self {
+        Self(HashMap::new())
+    }
+
+    /// Binds the key to the action. Replaces an existing binding of the same key.
+    pub fn bind(mut self, key: impl Into<KeyBinding>, action: TableAction) -> Self {
+        self.0.insert(key.into(), action);
+        self
+    }
+
+    /// Removes the binding of the key.
+    pub fn unbind(mut self, key: impl Into<KeyBinding>) -> Self {
+        self.0.remove(&key.into());
+        self
+    }
+
+    /// The action that is bound to the key.
+    pub fn action(&self, key: &KeyBinding) -> Option<TableAction> {
+        self.0.get(key).copied()
+    }
+}
+
+/// Provided as context by [`TableContent`](crate::TableContent) for the roving tabindex of the cells.
+#[derive(Clone, Copy)]
+pub(crate) struct FocusedCellContext(pub RwSignal<Option<CellPosition>>);
+
+/// Returns the `tabindex` for the cell with the given column index in the row that is currently being rendered.
+///
+/// Only the focused cell is reachable with the Tab key (`0`), all other cells have `-1`. If no cell has been
+/// focused yet, that is the first cell of the table. Use this in custom cell renderers to take part in the
+/// keyboard navigation.
+pub fn use_cell_tabindex(col_index: usize) -> Signal<i32> {
+    match (
+        use_context::<FocusedCellContext>(),
+        use_context::<RowIndex>(),
+    ) {
+        (Some(FocusedCellContext(focused_cell)), Some(RowIndex(row_index))) => {
+            let cell = CellPosition::new(row_index, col_index);
+            Signal::derive(move || {
+                if focused_cell.get().unwrap_or_default() == cell {
+                    0
+                } else {
+                    -1
+                }
+            })
+        }
+        _ => Signal::stored(-1),
+    }
+}
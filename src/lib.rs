@@ -6,13 +6,16 @@
 //!
 //! - **Easy to use** - yet powerful.
 //! - **Async data loading** - The data is loaded asynchronously. This allows to load data from a REST API or a database etc.
-//! - **Selection** - Can be turned off or single/multi select
+//! - **Selection** - Can be turned off or single/multi select rows or spreadsheet-style cell ranges
 //! - **Customization** - You can customize every aspect of the table by plugging in your own components for rendering rows, cells, headers. See [Custom Renderers](#custom-renderers) for more information.
 //! - **Headless** - No default styling is applied to the table. You can fully customize the classes that are applied to the table. See [Classes customization](#classes-customization) for more information.
 //! - **Sorting** - Optional. If turned on: Click on a column header to sort the table by that column. You can even sort by multiple columns.
 //! - **Virtualization** - Only the visible rows are rendered. This allows for very large tables.
 //! - **Pagination** - Instead of virtualization you can paginate the table.
 //! - **Caching** - Only visible rows are loaded and cached.
+//! - **Export** - Export the rows to CSV. See [Export](#export) for more information.
+//! - **Keyboard navigation** - Move between cells with the arrow keys and more. See [Keyboard Navigation](#keyboard-navigation).
+//! - **Clipboard** - Copy the selected rows to a spreadsheet and paste cells from it. See [Clipboard](#clipboard) for more information.
 //! - **Editing** - Optional. You can provide custom renderers for editable cells. See [Editable Cells](#editable-cells) for more information.
 //!
 //! # Usage
@@ -97,6 +100,98 @@
 //! and the [serverfn_sqlx example](https://github.com/Synphonyte/leptos-struct-table/blob/master/examples/serverfn_sqlx/src/data_provider.rs)
 //! for working demo projects that implement these traits.
 //!
+//! # Sorting
+//!
+//! The data provider generated by `#[table(impl_vec_data_provider)]` sorts by all sorted columns in order of priority
+//! and keeps the order of equal rows. By default it compares the fields by their type's ordering. To sort strings
+//! naturally (`"item2"` before `"item10"`), without regard to case, by the rules of a language or by a derived key,
+//! use the field attributes `sort_by` and `sort_key`. [`cmp_natural`], [`cmp_case_insensitive`],
+//! [`cmp_natural_case_insensitive`] and [`LocaleCollator`] are ready-made comparators for strings.
+//!
+//! ```
+//! # use leptos::prelude::*;
+//! # use leptos_struct_table::*;
+//! # use std::collections::VecDeque;
+//! #[derive(TableRow, Clone)]
+//! #[table(sortable, impl_vec_data_provider)]
+//! pub struct File {
+//!     #[table(sort_by = "cmp_natural_case_insensitive")]
+//!     name: String,
+//!     #[table(sort_key = "File::extension")]
+//!     kind: String,
+//! }
+//!
+//! impl File {
+//!     fn extension(&self) -> Option<&str> {
+//!         self.name.rsplit_once('.').map(|(_, extension)| extension)
+//!     }
+//! }
+//! #
+//! # let file = |name: &str| File { name: name.to_string(), kind: String::new() };
+//! # let mut files = vec![file("img10.png"), file("notes.txt"), file("IMG2.png")];
+//! # files.set_sorting(&VecDeque::from([(0, ColumnSort::Ascending)]));
+//! # assert_eq!(files.iter().map(|f| f.name.as_str()).collect::<Vec<_>>(), ["IMG2.png", "img10.png", "notes.txt"]);
+//! # files.set_sorting(&VecDeque::from([(1, ColumnSort::Descending), (0, ColumnSort::Descending)]));
+//! # assert_eq!(files.iter().map(|f| f.name.as_str()).collect::<Vec<_>>(), ["notes.txt", "img10.png", "IMG2.png"]);
+//! ```
+//!
+//! If you implement [`TableDataProvider::set_sorting`] yourself, [`sort_rows_by`] sorts the rows the same way
+//! with a comparator per column.
+//!
+//! Empty values of `Option` fields are placed first or last with the field attribute `nulls = "first"` or `"last"`
+//! ([`TableRow::nulls_ordering`]). It is applied by the `impl_vec_data_provider` sorting and by
+//! [`TableRow::sorting_to_sql`] which emits `NULLS FIRST` / `NULLS LAST` (or emulates them for databases that lack
+//! them) so the client and the database sort the same way. At runtime pass a [`NullsOrdering`] to
+//! [`sort_rows_by_with_nulls`] and [`SqlSortingOptions::nulls_ordering`].
+//!
+//! Columns that are named differently in the database or live on a joined table declare their database column with the
+//! field attribute `db_column = "c.first_name"`, columns that are sorted by an SQL expression `sort_expr = "lower(name)"`.
+//! [`SqlSortingOptions`] quote the identifiers for a [`SqlDialect`] and can override both at runtime.
+//!
+//! If you build your queries with `sea-query` or `diesel` instead of SQL strings, enable the feature of the same name
+//! and use `sea_query::order_by_sorting` or `diesel::order_by_sorting` to map the sorting to typed columns as well as
+//! `sea_query::limit_range` or `diesel::paginate` for the requested range of rows.
+//!
+//! The initial sorting is declared with the field attributes `default_sort` and `default_sort_priority`
+//! ([`TableRow::default_sorting`]). [`TableController::reset_sorting`] restores it.
+//!
+//! ```
+//! # use leptos::prelude::*;
+//! # use leptos_struct_table::*;
+//! #[derive(TableRow, Clone)]
+//! #[table(sortable, impl_vec_data_provider)]
+//! pub struct Article {
+//!     #[table(default_sort = "asc", default_sort_priority = 1)]
+//!     title: String,
+//!     #[table(default_sort = "desc", default_sort_priority = 0)]
+//!     published_at: u32,
+//! }
+//!
+//! # fn demo(rows: Vec<Article>) -> impl IntoView {
+//! let controller = TableController::<Article>::default();
+//!
+//! view! {
+//!     <button on:click={
+//!         let controller = controller.clone();
+//!         move |_| controller.reset_sorting()
+//!     }>"Reset sorting"</button>
+//!     <table>
+//!         <TableContent rows controller scroll_container="html" />
+//!     </table>
+//! }
+//! # }
+//! ```
+//!
+//! To choose the initial sorting at runtime pass it to the `default_sorting` prop of [`TableContent`].
+//! [`sorting_by_name`] builds it from column names instead of indices.
+//!
+//! # Dynamic Rows
+//!
+//! If the columns of your table are only known at runtime (reports, ad-hoc queries, CSV uploads, ...)
+//! you can use [`DynamicRow`] instead of deriving [`TableRow`] for a struct. The columns are described
+//! by a list of [`DynamicColumn`]s that specify the title, classes, sortability, an optional accessor and
+//! the cell renderer of each column. Please refer to [`DynamicRow`] for an example.
+//!
 //! # Macro options
 //!
 //! The `#[table(...)]` attribute can be used to customize the generated component. The following options are available:
@@ -106,9 +201,14 @@
 //! These attributes can be applied to the struct itself.
 //!
 //! - **`sortable`** - Specifies that the table should be sortable. This makes the header titles clickable to control sorting.
-//!   You can specify two sorting modes with the prop `sorting_mode` on the `TableContent` component:
+//!   You can specify three sorting modes with the prop `sorting_mode` on the `TableContent` component:
 //!   - `sorting_mode=SortingMode::MultiColumn` (the default) allows the table to be sorted by multiple columns ordered by priority.
 //!   - `sorting_mode=SortingMode::SingleColumn"` allows the table to be sorted by a single column. Clicking on another column will simply replace the sorting column.
+//!   - `sorting_mode=SortingMode::MultiColumnWithModifier` sorts by the clicked column only. Shift+Click adds more columns.
+//!
+//!   The prop `sorting_options` takes [`SortingOptions`] to limit the number of sorted columns and to skip the unsorted
+//!   state when clicking through the directions. Columns that should be sorted descending first (e.g. dates) get the
+//!   field attribute `sort_first`.
 //!
 //!   See the [simple example](https://github.com/synphonyte/leptos-struct-table/blob/master/examples/simple/src/main.rs) and the
 //!   [selectable example](https://github.com/synphonyte/leptos-struct-table/blob/master/examples/selectable/src/main.rs) for more information.
@@ -117,6 +217,8 @@
 //! - **`head_cell_renderer`** - Specifies the name of the header cell renderer component. Used to customize the rendering of header cells. Defaults to [`DefaultTableHeaderRenderer`]. See the [custom_renderers_svg example](https://github.com/Synphonyte/leptos-struct-table/blob/master/examples/custom_renderers_svg/src/main.rs) for more information.
 //! - **`impl_vec_data_provider`** - If given, then [`TableDataProvider`] is automatically implemented for `Vec<ThisStruct>` to allow
 //!   for easy local data use. See the [simple example](https://github.com/synphonyte/leptos-struct-table/blob/master/examples/simple/src/main.rs) for more information.
+//! - **`impl_row_text`** - If given, then [`TableRowText`] is automatically implemented with the same format options as the table.
+//!   This is used by [`export_table`] and [`TableClipboard`]. Requires [`CellText`] for the types of all fields.
 //! - **`row_type`** - Specifies the type of the rows in the table. Defaults to the struct that this is applied to. See the [custom_type example](https://github.com/synphonyte/leptos-struct-table/blob/master/examples/custom_type/src/main.rs) for more information.
 //! - **`i18n`** - Allows to specify the i18n scope for all fields of the struct as well as the `i18n` module path which defaults to `crate::i18n`. See [I18n](#i18n) for more information.
 //!
@@ -129,6 +231,20 @@
 //! - **`cell_class`** - Specifies the classes that are applied to the body cells in the field's column. Can be used in conjunction with `classes_provider` to customize the classes.
 //! - **`skip`** - Specifies that the field should be skipped. This is useful for fields that are not displayed in the table.
 //! - **`skip_sort`** - Only applies if `sortable` is set on the struct. Specifies that the field should not be used for sorting. Clicking it's header will not do anything.
+//! - **`sort_first`** - Only applies if `sortable` is set on the struct. The direction (`"asc"` or `"desc"`) the column is sorted
+//!   in when its header is clicked first. Defaults to `"asc"`. Descending often makes more sense for dates or scores.
+//! - **`nulls`** - Only applies to `Option` fields. Places the `None` values `"first"` or `"last"` regardless of the sort direction,
+//!   on the client as well as in SQL. See [`TableRow::nulls_ordering`].
+//! - **`db_column`** - The database column that [`TableRow::sorting_to_sql`] sorts by instead of the field name, e.g. `db_column = "c.first_name"`.
+//!   It is quoted according to the [`SqlDialect`] of [`SqlSortingOptions`].
+//! - **`sort_expr`** - A raw SQL expression that [`TableRow::sorting_to_sql`] sorts by instead of the field name, e.g. `sort_expr = "lower(name)"`.
+//! - **`default_sort`** - Only applies if `sortable` is set on the struct. Sorts the table by the field (`"asc"` or `"desc"`)
+//!   when it's rendered first and after [`TableController::reset_sorting`]. See [`TableRow::default_sorting`].
+//! - **`default_sort_priority`** - The priority of the `default_sort` of this field if more than one field has one. Lower numbers come first.
+//! - **`sort_by`** - Only applies together with `impl_vec_data_provider`. Specifies a function `fn(&T, &T) -> Ordering` that compares
+//!   the values of the field in ascending order instead of their type's ordering, e.g. `sort_by = "cmp_natural"`. See [Sorting](#sorting).
+//! - **`sort_key`** - Only applies together with `impl_vec_data_provider`. Specifies a function that takes the row by reference and
+//!   returns the key the column is sorted by. Use this to sort by a value derived from one or more fields.
 //! - **`skip_header`** - Makes the title of the field not be displayed in the head row.
 //! - **`title`** - Specifies the title that is displayed in the header cell. Defaults to the field name converted to title case (`this_field` becomes `"This Field"`).
 //! - **`renderer`** - Specifies the name of the cell renderer component. Used to customize the rendering of cells.
@@ -150,6 +266,10 @@
     feature = "rust_decimal",
     doc = r##"- [`rust_decimal::DecimalNumberRenderOptions`]"##
 )]
+#![cfg_attr(
+    feature = "serde_json",
+    doc = r##"- [`serde_json::RenderJsonOptions`]"##
+)]
 //!
 //!
 #![cfg_attr(
@@ -176,9 +296,14 @@ pub struct TemperatureMeasurement {
 //! # Features
 //!
 //! - **`chrono`** - Adds support for types from the crate `chrono`.
+//! - **`diesel`** - Adds `diesel::order_by_sorting` and `diesel::paginate` to build queries with `diesel`.
+//! - **`leptos_router`** - Adds `use_table_url_sync` to sync the sorting, page and filters of a table with the URL.
 //! - **`rust_decimal`** - Adds support for types from the crate `rust_decimal`.
+//! - **`sea-query`** - Adds `sea_query::order_by_sorting` and `sea_query::limit_range` to build queries with `sea-query`.
+//! - **`serde_json`** - Adds support for `serde_json::Value`. Use `#[table(marker = "Value")]` on such fields.
 //! - **`time`** - Adds support for types from the crate `time`.
 //! - **`uuid`** - Adds support for types from the crate `uuid`.
+//! - **`xlsx`** - Adds the `XlsxExporter` to export tables as Excel workbooks. See [Export](#export).
 //!
 //! # Classes Customization
 //!
@@ -381,10 +506,87 @@ pub struct TemperatureMeasurement {
 //!
 //! The following options are available. Check their docs for more details.
 //! - [`DisplayStrategy::Virtualization`] (default)
+//! - [`DisplayStrategy::BottomAnchored`]
 //! - [`DisplayStrategy::InfiniteScroll`]
+//! - [`DisplayStrategy::LoadMore`]
 //! - [`DisplayStrategy::Pagination`]
 //!
-//! Please have a look at the [pagination example](https://github.com/Synphonyte/leptos-struct-table/tree/master/examples/pagination/src/main.rs) for more information on how to use pagination.
+//! Please have a look at the [pagination example](https://github.com/Synphonyte/leptos-struct-table/tree/master/examples/pagination/src/main.rs) for more information on how to use pagination. The [`Pager`] component
+//! renders ready-made controls for the [`PaginationController`] styled by your [`TableClassesProvider`].
+//!
+//! With [`DisplayStrategy::LoadMore`] a "Load more" row below the table appends the next rows until the data source
+//! runs out of rows. It can be replaced with the `load_more_renderer` prop, see [`DefaultLoadMoreRenderer`].
+//!
+//! For log viewers and chat histories [`DisplayStrategy::BottomAnchored`] starts at the end of the table and loads earlier
+//! rows as the user scrolls up. With `tail: true` it follows rows that are appended while the user is at the bottom.
+//! Let the table know about appended rows with [`TableDataProvider::track_appended`] or
+//! [`ReloadController::rows_appended`] so it keeps the loaded rows instead of reloading them.
+//!
+//! Rows don't need to have the same height. The rendered rows are measured as they change size and a [`RowHeights`]
+//! index estimates the rows that haven't been rendered yet, so the scroll position always maps to the right rows.
+//! If all rows have the same height, pass it to the `row_height` prop to skip the measurement entirely.
+//!
+//! To scroll to a row programmatically or to know which rows are visible, pass a [`TableController`] to the
+//! `controller` prop. Scrolling to a row works with every display strategy and shows the page of the row when paginated.
+//!
+//! Tables with a lot of columns can additionally be virtualized horizontally by passing a [`ColumnVirtualization`]
+//! to the `column_virtualization` prop. Then only the columns that are visible in the scroll container are rendered
+//! and spacer cells take the place of the others. The column widths are either given or measured from the rendered cells.
+//! Rows derived with `#[derive(TableRow)]` and [`DynamicRow`] support this out of the box. Custom [`TableRow`]
+//! implementations render their cells with [`render_column_window`].
+//!
+//! # Export
+//!
+//! The rows of a table can be exported with [`export_table`] which loads all rows from the data provider
+//! with the current sorting and streams them into a [`TableExporter`]. [`CsvExporter`] is provided and
+//! [`download_file`] lets the browser download the result. The cells are rendered as plain text by
+//! implementing [`TableRowText`] for your row type.
+//!
+//! With the feature `xlsx` enabled you can use the `XlsxExporter` to create Excel workbooks with typed cells.
+//! Implement [`TableRowText::export_value`] to export numbers and dates as such instead of text.
+//!
+//! # Keyboard Navigation
+//!
+//! The cells of the table can be navigated with the keyboard. Only the focused cell can be reached with the Tab key
+//! (roving tabindex) and the arrow keys, Home/End, PageUp/PageDown and Ctrl+Home/Ctrl+End move the focus.
+//! Rows that are not rendered yet are scrolled to (or their page is shown) before they are focused.
+//! Space and Enter select the focused row or cell. The keys can be changed with the `key_bindings` prop of
+//! [`TableContent`]. See [`KeyBindings`] for the defaults.
+//!
+//! Custom cell renderers take part in the navigation by setting the `tabindex` returned by [`use_cell_tabindex`].
+//!
+//! # Accessibility
+//!
+//! The default renderers expose the table as an ARIA grid. Every rendered row carries its `aria-rowindex`, the
+//! header cells `aria-sort` and the rows or cells `aria-selected` depending on the [`Selection`]. Since you render
+//! the table element yourself, spread [`TableController::grid_attributes`] onto it to add `role="grid"` together
+//! with `aria-rowcount` from the known row count, so screen readers report the real number of rows even though
+//! only a few of them are rendered.
+//!
+//! Changes of the sorting and the completion of loading can be announced through a live region. Pass
+//! `live_region_renderer=DefaultLiveRegionRenderer` to [`TableContent`] to render a visually hidden `<caption>`
+//! or your own renderer to place it elsewhere. Use the `format_announcement` prop to translate the messages of
+//! [`TableAnnouncement`].
+//!
+//! If you write custom renderers, use [`aria_row_index`] for the `aria-rowindex` of your rows.
+//!
+//! # Clipboard
+//!
+//! Pass a [`TableClipboard`] to the `clipboard` prop of [`TableContent`] to copy the selected rows with Ctrl+C
+//! or programmatically with [`TableClipboard::copy_selection`]. The rows are written as tab-separated text
+//! and as an HTML table so they can be pasted into Excel or Google Sheets. Like for the export the cells are
+//! rendered by [`TableRowText`].
+//!
+//! Pasting works the other way around: when the focus is inside a cell, a block of cells copied from a spreadsheet
+//! is written into the table starting at that cell through [`TableRowText::set_cell_text`]. Implement it with
+//! [`ParseCellValue`] to convert the text into your field types. The edited rows are reported by `on_change`
+//! and cells that fail to parse show the error message inline.
+//!
+//! # Persisting State
+//!
+//! [`use_persisted_table_state`] saves a [`TableState`] with the sorting, current page, page size as well as column
+//! widths, order and visibility to the local storage so it survives a page reload. The saved state is versioned and
+//! dropped when the columns of the row struct change.
 //!
 //! # I18n
 //!
@@ -402,40 +604,72 @@ pub struct TemperatureMeasurement {
 
 #![allow(non_snake_case)]
 
+mod aria;
 mod cell_value;
 #[cfg(feature = "chrono")]
 pub mod chrono;
 mod class_providers;
+mod clipboard;
+mod column_virtualization;
+mod compare;
 mod components;
 mod data_provider;
+#[cfg(feature = "diesel")]
+pub mod diesel;
 mod display_strategy;
+mod dynamic_row;
 mod events;
+mod export;
+mod keyboard;
 mod loaded_rows;
 mod reload_controller;
+mod row_heights;
 mod row_reader;
 #[cfg(feature = "rust_decimal")]
 pub mod rust_decimal;
+#[cfg(feature = "sea-query")]
+pub mod sea_query;
 mod selection;
+#[cfg(feature = "serde_json")]
+pub mod serde_json;
 mod sorting;
+mod sql;
+mod table_controller;
 mod table_row;
+mod table_state;
 #[cfg(feature = "time")]
 pub mod time;
+#[cfg(feature = "leptos_router")]
+mod url_sync;
 #[cfg(feature = "uuid")]
 mod uuid;
 
+pub use aria::*;
 pub use cell_value::*;
 pub use class_providers::*;
+pub use clipboard::*;
+pub use column_virtualization::*;
+pub use compare::*;
 pub use components::*;
 pub use data_provider::*;
 pub use display_strategy::*;
+pub use dynamic_row::*;
 pub use events::*;
+pub use export::*;
+pub use keyboard::*;
 pub use leptos_struct_table_macro::TableRow;
 pub use loaded_rows::RowState;
 pub use reload_controller::*;
+pub use row_heights::*;
 pub use row_reader::*;
 pub use selection::*;
 pub use sorting::*;
+pub use sql::*;
+pub use table_controller::*;
 pub use table_row::*;
+pub use table_state::*;
+#[cfg(feature = "leptos_router")]
+pub use url_sync::*;
 
 use serde::{Deserialize, Serialize};
 use std::marker::PhantomData;
@@ -458,6 +692,15 @@ impl ColumnSort {
         }
     }
 
+    /// Returns the value for the `aria-sort` attribute of a column header.
+    pub fn as_aria_sort(&self) -> &'static str {
+        match self {
+            ColumnSort::Ascending => "ascending",
+            ColumnSort::Descending => "descending",
+            _ => "none",
+        }
+    }
+
     /// Returns the SQL sort order (ASC or DESC) or `None` if `ColumnSort::None`.
     pub fn as_sql(&self) -> Option<&'static str> {
         match self {
@@ -50,6 +50,11 @@ impl<T: Send + Sync + 'static> LoadedRows<T> {
         self.rows.len()
     }
 
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<&RowState<T>> {
+        self.rows.get(index)
+    }
+
     #[inline]
     pub fn resize(&mut self, len: usize) {
         self.rows.resize(len, RowState::Placeholder);
@@ -5,20 +5,37 @@ use leptos::prelude::*;
 /// See the [paginated_rest_datasource example](https://github.com/Synphonyte/leptos-struct-table/blob/master/examples/paginated_rest_datasource/src/main.rs)
 /// for how to use.
 #[derive(Copy, Clone)]
-pub struct ReloadController(Trigger);
+pub struct ReloadController {
+    reload: Trigger,
+    append: Trigger,
+}
 
 impl Default for ReloadController {
     fn default() -> Self {
-        Self(Trigger::new())
+        Self {
+            reload: Trigger::new(),
+            append: Trigger::new(),
+        }
     }
 }
 
 impl ReloadController {
     pub fn reload(&self) {
-        self.0.notify();
+        self.reload.notify();
     }
 
     pub fn track(&self) {
-        self.0.track();
+        self.reload.track();
+    }
+
+    /// Tells the table that rows were appended to the end of the data. Unlike [`reload`](Self::reload) the
+    /// loaded rows, their heights, the selection and the focus are kept. Only the row count is fetched again
+    /// and the new rows are loaded when they are shown.
+    pub fn rows_appended(&self) {
+        self.append.notify();
+    }
+
+    pub fn track_appended(&self) {
+        self.append.track();
     }
 }
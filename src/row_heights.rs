@@ -0,0 +1,179 @@
+/// An index of the heights of the rows used by the virtualization of [`TableContent`](crate::TableContent)
+/// to map between scroll positions and row indices.
+///
+/// Rows that have been measured contribute their actual height. All other rows are estimated with the
+/// average height of the measured rows (or the initial estimate as long as nothing has been measured).
+/// Offsets and lookups are computed from prefix sums in `O(log n)`.
+///
+/// ```
+/// # use leptos_struct_table::*;
+/// let mut row_heights = RowHeights::new(20.0);
+///
+/// row_heights.set_height(0, 40.0);
+/// row_heights.set_height(1, 60.0);
+///
+/// // the unmeasured rows are estimated with the average of the measured ones
+/// assert_eq!(row_heights.estimated_height(), 50.0);
+/// assert_eq!(row_heights.offset_of(3), 150.0);
+///
+/// assert_eq!(row_heights.index_at(0.0), 0);
+/// assert_eq!(row_heights.index_at(99.0), 1);
+/// assert_eq!(row_heights.index_at(100.0), 2);
+/// assert_eq!(row_heights.index_at(1000.0), 20);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct RowHeights {
+    initial_estimate: f64,
+    heights: Vec<Option<f64>>,
+    /// Fenwick tree of the measured heights
+    height_tree: Vec<f64>,
+    /// Fenwick tree of the number of measured rows
+    count_tree: Vec<usize>,
+    measured_sum: f64,
+    measured_count: usize,
+}
+
+impl RowHeights {
+    /// Creates an empty index that estimates every row with `estimated_height` pixels.
+    pub fn new(estimated_height: f64) -> Self {
+        Self {
+            initial_estimate: estimated_height,
+            heights: vec![],
+            height_tree: vec![],
+            count_tree: vec![],
+            measured_sum: 0.0,
+            measured_count: 0,
+        }
+    }
+
+    /// The height in pixels that is assumed for rows that haven't been measured.
+    pub fn estimated_height(&self) -> f64 {
+        if self.measured_count == 0 {
+            self.initial_estimate
+        } else {
+            self.measured_sum / self.measured_count as f64
+        }
+    }
+
+    /// The measured height of the row or `None` if it hasn't been measured yet.
+    pub fn measured_height(&self, index: usize) -> Option<f64> {
+        self.heights.get(index).copied().flatten()
+    }
+
+    /// The measured or estimated height of the row.
+    pub fn height(&self, index: usize) -> f64 {
+        self.measured_height(index)
+            .unwrap_or_else(|| self.estimated_height())
+    }
+
+    /// Records the measured height of the row. Returns `true` if it differs from the previous measurement.
+    pub fn set_height(&mut self, index: usize, height: f64) -> bool {
+        let previous_height = self.measured_height(index);
+
+        if previous_height.is_some_and(|previous_height| (previous_height - height).abs() < 0.5) {
+            return false;
+        }
+
+        if index >= self.heights.len() {
+            self.grow((index + 1).max(self.heights.len() * 2));
+        }
+
+        let (height_delta, count_delta) = match previous_height {
+            Some(previous_height) => (height - previous_height, 0),
+            None => (height, 1),
+        };
+
+        self.heights[index] = Some(height);
+        self.measured_sum += height_delta;
+        self.measured_count += count_delta;
+
+        let mut node = index + 1;
+        while node <= self.heights.len() {
+            self.height_tree[node - 1] += height_delta;
+            self.count_tree[node - 1] += count_delta;
+            node += node & node.wrapping_neg();
+        }
+
+        true
+    }
+
+    /// The distance in pixels from the top of the first row to the top of the row with the given index.
+    pub fn offset_of(&self, index: usize) -> f64 {
+        let estimated_height = self.estimated_height();
+        let indexed_len = index.min(self.heights.len());
+
+        let mut height_sum = 0.0;
+        let mut count = 0;
+        let mut node = indexed_len;
+        while node > 0 {
+            height_sum += self.height_tree[node - 1];
+            count += self.count_tree[node - 1];
+            node &= node - 1;
+        }
+
+        height_sum + (index - count) as f64 * estimated_height
+    }
+
+    /// The index of the row that contains the given offset in pixels from the top of the first row.
+    pub fn index_at(&self, offset: f64) -> usize {
+        let estimated_height = self.estimated_height();
+        let len = self.heights.len();
+
+        // descend the Fenwick trees to find the last row that starts at or before the offset
+        let mut index = 0;
+        let mut remaining = offset;
+        let mut step = if len == 0 {
+            0
+        } else {
+            1 << (usize::BITS - 1 - len.leading_zeros())
+        };
+        while step > 0 {
+            let node = index + step;
+            if node <= len {
+                let node_height = self.height_tree[node - 1]
+                    + (step - self.count_tree[node - 1]) as f64 * estimated_height;
+
+                if node_height <= remaining {
+                    index = node;
+                    remaining -= node_height;
+                }
+            }
+            step >>= 1;
+        }
+
+        if index < len || estimated_height <= 0.0 {
+            index
+        } else {
+            index + (remaining / estimated_height).floor() as usize
+        }
+    }
+
+    /// Forgets all measurements. The current estimate is kept as the initial estimate.
+    pub fn clear(&mut self) {
+        *self = Self::new(self.estimated_height());
+    }
+
+    fn grow(&mut self, len: usize) {
+        self.heights.resize(len, None);
+
+        // rebuild the trees in O(n)
+        self.height_tree = self
+            .heights
+            .iter()
+            .map(|height| height.unwrap_or_default())
+            .collect();
+        self.count_tree = self
+            .heights
+            .iter()
+            .map(|height| height.is_some() as usize)
+            .collect();
+
+        for node in 1..=len {
+            let parent = node + (node & node.wrapping_neg());
+            if parent <= len {
+                self.height_tree[parent - 1] += self.height_tree[node - 1];
+                self.count_tree[parent - 1] += self.count_tree[node - 1];
+            }
+        }
+    }
+}
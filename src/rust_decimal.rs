@@ -23,10 +23,42 @@ pub struct DecimalNumberRenderOptions {
 impl CellValue<Decimal> for Decimal {
     type RenderOptions = DecimalNumberRenderOptions;
     fn render_value(self, options: Self::RenderOptions) -> impl IntoView {
+        self.render_text(&options)
+    }
+}
+
+impl CellText<Decimal> for Decimal {
+    fn render_text(&self, options: &Self::RenderOptions) -> String {
         if let Some(value) = options.precision.as_ref() {
             format!("{:.value$}", self)
         } else {
             self.to_string()
         }
     }
+
+    /// Exports the exact value. The scale of the decimal is kept as the number format.
+    ///
+    /// ```
+    /// # use leptos_struct_table::*;
+    /// # use ::rust_decimal::Decimal;
+    /// # use leptos_struct_table::rust_decimal::DecimalNumberRenderOptions;
+    /// # use std::str::FromStr;
+    /// let value = Decimal::from_str("12345678901234567890.12").unwrap();
+    ///
+    /// assert_eq!(
+    ///     value.export_value(&DecimalNumberRenderOptions::default()),
+    ///     ExportValue::Decimal {
+    ///         value: "12345678901234567890.12".to_string(),
+    ///         precision: Some(2),
+    ///     }
+    /// );
+    /// ```
+    fn export_value(&self, options: &Self::RenderOptions) -> ExportValue {
+        ExportValue::Decimal {
+            value: self.to_string(),
+            precision: options.precision.or(Some(self.scale() as usize)),
+        }
+    }
 }
+
+parse_cell_value_from_str![Decimal];
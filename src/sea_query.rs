@@ -0,0 +1,106 @@
+//! Support for the [::sea_query] query builder.
+//!
+//! Turns the sorting of a table and the range of rows to load into `ORDER BY`, `LIMIT` and `OFFSET`
+//! of a [`SelectStatement`].
+
+use crate::{ColumnSort, NullsOrder, NullsOrdering, TableRow};
+use ::sea_query::{IntoColumnRef, NullOrdering, Order, SelectStatement};
+use std::collections::VecDeque;
+use std::ops::Range;
+
+/// Returns the sea-query [`Order`] of the sort or `None` if `ColumnSort::None`.
+pub fn order(sort: ColumnSort) -> Option<Order> {
+    match sort {
+        ColumnSort::Ascending => Some(Order::Asc),
+        ColumnSort::Descending => Some(Order::Desc),
+        ColumnSort::None => None,
+    }
+}
+
+/// Adds the sorting of the table to the `ORDER BY` of the query.
+///
+/// `column` maps the column name ([`TableRow::col_name`]) to the column identifier of the query.
+/// Columns for which it returns `None` are not sorted by. `NULL` values are placed as given by
+/// [`TableRow::nulls_ordering`].
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_struct_table::{ColumnSort, TableRow};
+/// use leptos_struct_table::sea_query::{limit_range, order_by_sorting};
+/// # use ::sea_query::{Iden, PostgresQueryBuilder, Query};
+/// # use std::collections::VecDeque;
+/// #[derive(TableRow, Clone)]
+/// struct Customer {
+///     name: String,
+///     signed_up_at: u64,
+/// }
+///
+/// #[derive(Iden)]
+/// enum Customers {
+///     Table,
+///     Name,
+///     CreatedAt,
+/// }
+///
+/// let sorting = VecDeque::from([(1, ColumnSort::Descending), (0, ColumnSort::Ascending)]);
+///
+/// let mut query = Query::select();
+/// query.column(Customers::Name).from(Customers::Table);
+///
+/// order_by_sorting::<Customer, _>(&mut query, &sorting, |col_name| match col_name {
+///     "name" => Some(Customers::Name),
+///     "signed_up_at" => Some(Customers::CreatedAt),
+///     _ => None,
+/// });
+/// limit_range(&mut query, 20..30);
+///
+/// assert_eq!(
+///     query.to_string(PostgresQueryBuilder),
+///     r#"SELECT "name" FROM "customers" ORDER BY "created_at" DESC, "name" ASC LIMIT 10 OFFSET 20"#
+/// );
+/// ```
+pub fn order_by_sorting<Row, C>(
+    query: &mut SelectStatement,
+    sorting: &VecDeque<(usize, ColumnSort)>,
+    column: impl FnMut(&'static str) -> Option<C>,
+) where
+    Row: TableRow,
+    C: IntoColumnRef,
+{
+    order_by_sorting_with_nulls::<Row, C>(query, sorting, &Row::nulls_ordering(), column);
+}
+
+/// Same as [`order_by_sorting`] but places the `NULL` values of the columns in `nulls_ordering` first or last.
+pub fn order_by_sorting_with_nulls<Row, C>(
+    query: &mut SelectStatement,
+    sorting: &VecDeque<(usize, ColumnSort)>,
+    nulls_ordering: &NullsOrdering,
+    mut column: impl FnMut(&'static str) -> Option<C>,
+) where
+    Row: TableRow,
+    C: IntoColumnRef,
+{
+    for (col_index, sort) in sorting {
+        let (Some(order), Some(column)) = (order(*sort), column(Row::col_name(*col_index))) else {
+            continue;
+        };
+
+        match nulls_ordering.get(*col_index) {
+            Some(NullsOrder::First) => {
+                query.order_by_with_nulls(column, order, NullOrdering::First);
+            }
+            Some(NullsOrder::Last) => {
+                query.order_by_with_nulls(column, order, NullOrdering::Last);
+            }
+            None => {
+                query.order_by(column, order);
+            }
+        }
+    }
+}
+
+/// Sets `LIMIT` and `OFFSET` of the query to load the given range of rows like it's requested by
+/// [`TableDataProvider::get_rows`](crate::TableDataProvider::get_rows).
+pub fn limit_range(query: &mut SelectStatement, range: Range<usize>) {
+    query.limit(range.len() as u64).offset(range.start as u64);
+}
@@ -1,5 +1,6 @@
 use leptos::prelude::*;
 use std::collections::HashSet;
+use std::ops::RangeInclusive;
 
 /// Type of selection together with the `RwSignal` to hold the selection
 #[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
@@ -15,6 +16,13 @@ pub enum Selection {
     /// Allow multiple rows to be selected at a time. Each entry in the `Vec`
     /// is the index of a selected row.
     Multiple(RwSignal<HashSet<usize>>),
+
+    /// Spreadsheet-style selection of rectangular cell ranges.
+    ///
+    /// Click a cell to select it, drag or Shift+Click to span a range and Ctrl+Click (Cmd+Click) to add
+    /// another range. Shift+Arrow keys extend the active range. Cell renderers can read whether they are
+    /// selected with [`use_cell_selected`].
+    Cells(RwSignal<CellSelection>),
 }
 
 impl Selection {
@@ -28,6 +36,190 @@ impl Selection {
             Selection::Multiple(selected_indices) => {
                 selected_indices.write().clear();
             }
+            Selection::Cells(cell_selection) => {
+                cell_selection.write().ranges.clear();
+            }
+        }
+    }
+}
+
+/// The position of a cell in the table body. Both indices start at 0.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+pub struct CellPosition {
+    pub row_index: usize,
+    pub col_index: usize,
+}
+
+impl CellPosition {
+    pub fn new(row_index: usize, col_index: usize) -> Self {
+        Self {
+            row_index,
+            col_index,
+        }
+    }
+}
+
+/// A rectangular range of cells spanned by the cell where the selection started (`anchor`)
+/// and the cell where it currently ends (`focus`). Both cells are included.
+///
+/// ```
+/// # use leptos_struct_table::*;
+/// let range = CellRange::new(CellPosition::new(4, 2), CellPosition::new(1, 3));
+///
+/// assert_eq!(range.rows(), 1..=4);
+/// assert_eq!(range.cols(), 2..=3);
+/// assert!(range.contains(CellPosition::new(2, 3)));
+/// assert!(!range.contains(CellPosition::new(2, 1)));
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct CellRange {
+    pub anchor: CellPosition,
+    pub focus: CellPosition,
+}
+
+impl CellRange {
+    pub fn new(anchor: CellPosition, focus: CellPosition) -> Self {
+        Self { anchor, focus }
+    }
+
+    /// A range that only contains the given cell.
+    pub fn single(cell: CellPosition) -> Self {
+        Self::new(cell, cell)
+    }
+
+    /// The indices of the rows in this range in ascending order.
+    pub fn rows(&self) -> RangeInclusive<usize> {
+        self.anchor.row_index.min(self.focus.row_index)
+            ..=self.anchor.row_index.max(self.focus.row_index)
+    }
+
+    /// The indices of the columns in this range in ascending order.
+    pub fn cols(&self) -> RangeInclusive<usize> {
+        self.anchor.col_index.min(self.focus.col_index)
+            ..=self.anchor.col_index.max(self.focus.col_index)
+    }
+
+    /// Whether the given cell is inside this range.
+    pub fn contains(&self, cell: CellPosition) -> bool {
+        self.rows().contains(&cell.row_index) && self.cols().contains(&cell.col_index)
+    }
+}
+
+/// The state of [`Selection::Cells`]. Consists of any number of cell ranges.
+/// The last range is the active one that is extended by dragging and Shift+Click.
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+pub struct CellSelection {
+    pub ranges: Vec<CellRange>,
+}
+
+impl CellSelection {
+    /// Whether no cell is selected.
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// Whether the given cell is inside any of the ranges.
+    pub fn contains(&self, cell: CellPosition) -> bool {
+        self.ranges.iter().any(|range| range.contains(cell))
+    }
+
+    /// The range that was added last.
+    pub fn active_range(&self) -> Option<CellRange> {
+        self.ranges.last().copied()
+    }
+
+    /// The anchor of the active range.
+    pub fn anchor(&self) -> Option<CellPosition> {
+        self.active_range().map(|range| range.anchor)
+    }
+
+    /// The focus of the active range.
+    pub fn focus(&self) -> Option<CellPosition> {
+        self.active_range().map(|range| range.focus)
+    }
+
+    /// Selects only the given cell.
+    pub fn select(&mut self, cell: CellPosition) {
+        self.ranges.clear();
+        self.ranges.push(CellRange::single(cell));
+    }
+
+    /// Adds a new range that only contains the given cell and makes it the active one.
+    pub fn add(&mut self, cell: CellPosition) {
+        self.ranges.push(CellRange::single(cell));
+    }
+
+    /// Moves the focus of the active range to the given cell. Selects the cell if there is no range yet.
+    pub fn extend_to(&mut self, cell: CellPosition) {
+        match self.ranges.last_mut() {
+            Some(range) => range.focus = cell,
+            None => self.select(cell),
+        }
+    }
+
+    /// The indices of all rows that contain a selected cell in ascending order.
+    pub fn row_indices(&self) -> Vec<usize> {
+        let mut row_indices = self
+            .ranges
+            .iter()
+            .flat_map(|range| range.rows())
+            .collect::<Vec<_>>();
+        row_indices.sort_unstable();
+        row_indices.dedup();
+        row_indices
+    }
+}
+
+/// Provided as context by [`TableContent`](crate::TableContent) if [`Selection::Cells`] is used.
+#[derive(Clone, Copy)]
+pub(crate) struct CellSelectionContext {
+    pub cell_selection: RwSignal<CellSelection>,
+    pub selected_class: Callback<(usize, usize), String>,
+    pub anchor_class: Callback<(usize, usize), String>,
+}
+
+/// Returns whether the cell with the given column index in the row that is currently being rendered
+/// is part of the [`Selection::Cells`]. Use this in custom cell renderers.
+pub fn use_cell_selected(col_index: usize) -> Signal<bool> {
+    match (
+        use_context::<CellSelectionContext>(),
+        use_context::<crate::RowIndex>(),
+    ) {
+        (Some(context), Some(crate::RowIndex(row_index))) => {
+            let cell = CellPosition::new(row_index, col_index);
+            Signal::derive(move || context.cell_selection.read().contains(cell))
+        }
+        _ => Signal::stored(false),
+    }
+}
+
+/// Returns the classes for the cell with the given column index in the row that is currently being rendered
+/// from [`TableClassesProvider::selected_cell`](crate::TableClassesProvider::selected_cell) and
+/// [`TableClassesProvider::anchor_cell`](crate::TableClassesProvider::anchor_cell).
+/// Empty if the cell is not selected. Use this in custom cell renderers.
+pub fn use_cell_selection_class(col_index: usize) -> Signal<String> {
+    match (
+        use_context::<CellSelectionContext>(),
+        use_context::<crate::RowIndex>(),
+    ) {
+        (Some(context), Some(crate::RowIndex(row_index))) => {
+            let cell = CellPosition::new(row_index, col_index);
+            Signal::derive(move || {
+                let cell_selection = context.cell_selection.read();
+
+                if !cell_selection.contains(cell) {
+                    String::new()
+                } else if cell_selection.anchor() == Some(cell) {
+                    format!(
+                        "{} {}",
+                        context.selected_class.run((row_index, col_index)),
+                        context.anchor_class.run((row_index, col_index))
+                    )
+                } else {
+                    context.selected_class.run((row_index, col_index))
+                }
+            })
         }
+        _ => Signal::stored(String::new()),
     }
 }
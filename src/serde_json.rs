@@ -0,0 +1,172 @@
+//! Support for [::serde_json] crate.
+
+use crate::*;
+use ::serde_json::Value;
+use leptos::prelude::*;
+use std::cmp::Ordering;
+
+#[derive(Clone, Default)]
+pub struct RenderJsonOptions {
+    /// Renders the value pretty printed inside a `<pre>` element. Defaults to compact rendering.
+    pub pretty: Option<bool>,
+    /// Only renders the value found at this key path. Keys are separated by dots and array
+    /// elements are addressed by their index, e.g. `"address.city"` or `"tags.0"`.
+    /// JSON pointers like `"/address/city"` are supported as well.
+    pub path: Option<String>,
+    /// Truncates the rendered text to this number of characters followed by `…`.
+    pub max_length: Option<usize>,
+}
+
+impl RenderJsonOptions {
+    /// Renders the value to plain text according to these options.
+    /// Strings are rendered without quotes and `null` as an empty string.
+    pub fn render_text(&self, value: &Value) -> String {
+        let value = match self.path.as_deref() {
+            Some(path) => match json_value_at_path(value, path) {
+                Some(value) => value,
+                None => return String::new(),
+            },
+            None => value,
+        };
+
+        let text = match value {
+            Value::Null => String::new(),
+            Value::String(string) => string.clone(),
+            value if self.pretty.unwrap_or_default() => {
+                ::serde_json::to_string_pretty(value).unwrap_or_default()
+            }
+            value => value.to_string(),
+        };
+
+        match self.max_length {
+            Some(max_length) if text.chars().count() > max_length => {
+                text.chars().take(max_length).chain(['…']).collect()
+            }
+            _ => text,
+        }
+    }
+}
+
+/// Returns the value at the given key path. See [`RenderJsonOptions::path`] for the syntax.
+pub fn json_value_at_path<'a>(value: &'a Value, path: &str) -> Option<&'a Value> {
+    if path.starts_with('/') {
+        return value.pointer(path);
+    }
+
+    path.split('.')
+        .filter(|key| !key.is_empty())
+        .try_fold(value, |value, key| match value {
+            Value::Object(map) => map.get(key),
+            Value::Array(array) => array.get(key.parse::<usize>().ok()?),
+            _ => None,
+        })
+}
+
+/// Implementation for [`Value`] to work with the [`TableRow`] derive and the [`DefaultTableCellRenderer`]
+/// ```
+/// # use leptos_struct_table::*;
+/// # use leptos::prelude::*;
+/// # use ::serde_json::Value;
+/// #[derive(TableRow, Clone)]
+/// #[table]
+/// struct SomeStruct {
+///     #[table(marker = "Value", format(path = "address.city", max_length = 20usize))]
+///     my_field: Value
+/// }
+/// ```
+impl CellValue<Value> for Value {
+    type RenderOptions = RenderJsonOptions;
+
+    fn render_value(self, options: Self::RenderOptions) -> impl IntoView {
+        let text = options.render_text(&self);
+
+        if options.pretty.unwrap_or_default() {
+            view! { <pre>{text}</pre> }.into_any()
+        } else {
+            text.into_any()
+        }
+    }
+}
+
+impl CellText<Value> for Value {
+    fn render_text(&self, options: &Self::RenderOptions) -> String {
+        options.render_text(self)
+    }
+}
+
+/// Parses the text as JSON. Text that isn't valid JSON is taken as a string.
+impl ParseCellValue<Value> for Value {
+    fn parse_cell_value(text: &str) -> Result<Self, CellParseError> {
+        Ok(::serde_json::from_str(text).unwrap_or_else(|_| Value::String(text.to_string())))
+    }
+}
+
+fn type_rank(value: &Value) -> u8 {
+    match value {
+        Value::Null => 0,
+        Value::Bool(_) => 1,
+        Value::Number(_) => 2,
+        Value::String(_) => 3,
+        Value::Array(_) => 4,
+        Value::Object(_) => 5,
+    }
+}
+
+/// A total order for mixed JSON values that can be used to sort columns of [`Value`]s on the client.
+///
+/// Values of different kinds are ordered `null < bool < number < string < array < object`.
+/// Numbers are compared numerically, arrays element by element and objects entry by entry.
+///
+/// ```
+/// # use leptos_struct_table::serde_json::cmp_json_values;
+/// # use ::serde_json::json;
+/// # use std::cmp::Ordering;
+/// assert_eq!(cmp_json_values(&json!(2), &json!(10.5)), Ordering::Less);
+/// assert_eq!(cmp_json_values(&json!(null), &json!(false)), Ordering::Less);
+/// assert_eq!(cmp_json_values(&json!("b"), &json!(1)), Ordering::Greater);
+/// ```
+pub fn cmp_json_values(a: &Value, b: &Value) -> Ordering {
+    match (a, b) {
+        (Value::Bool(a), Value::Bool(b)) => a.cmp(b),
+        (Value::Number(a), Value::Number(b)) => match (a.as_i64(), b.as_i64()) {
+            (Some(a), Some(b)) => a.cmp(&b),
+            _ => match (a.as_u64(), b.as_u64()) {
+                (Some(a), Some(b)) => a.cmp(&b),
+                _ => a
+                    .as_f64()
+                    .unwrap_or(f64::NAN)
+                    .total_cmp(&b.as_f64().unwrap_or(f64::NAN)),
+            },
+        },
+        (Value::String(a), Value::String(b)) => a.cmp(b),
+        (Value::Array(a), Value::Array(b)) => a
+            .iter()
+            .zip(b)
+            .map(|(a, b)| cmp_json_values(a, b))
+            .find(|ordering| ordering.is_ne())
+            .unwrap_or_else(|| a.len().cmp(&b.len())),
+        (Value::Object(a), Value::Object(b)) => a
+            .iter()
+            .zip(b)
+            .map(|((a_key, a), (b_key, b))| a_key.cmp(b_key).then_with(|| cmp_json_values(a, b)))
+            .find(|ordering| ordering.is_ne())
+            .unwrap_or_else(|| a.len().cmp(&b.len())),
+        (a, b) => type_rank(a).cmp(&type_rank(b)),
+    }
+}
+
+/// Arrays and objects are stored as their compact JSON text.
+impl From<Value> for DynamicValue {
+    fn from(value: Value) -> Self {
+        match value {
+            Value::Null => DynamicValue::Null,
+            Value::Bool(value) => DynamicValue::Bool(value),
+            Value::Number(number) => match number.as_i64() {
+                Some(value) => DynamicValue::Int(value),
+                None => DynamicValue::Float(number.as_f64().unwrap_or(f64::NAN)),
+            },
+            Value::String(value) => DynamicValue::Text(value),
+            value => DynamicValue::Text(value.to_string()),
+        }
+    }
+}
@@ -1,5 +1,7 @@
-use crate::{ColumnSort, TableHeadEvent};
-use std::collections::VecDeque;
+use crate::{ColumnSort, TableHeadEvent, TableRow};
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::collections::{HashMap, VecDeque};
 
 /// Sorting mode
 #[derive(PartialEq, Eq, Clone, Copy, Debug, Default)]
@@ -10,6 +12,10 @@ pub enum SortingMode {
     /// The table can be sorted by multiple columns ordered by priority
     #[default]
     MultiColumn,
+
+    /// A click sorts the table by only the clicked column. Shift+Click adds the column to the
+    /// sorting with the lowest priority or changes its direction if it's already sorted.
+    MultiColumnWithModifier,
 }
 
 impl SortingMode {
@@ -18,32 +24,365 @@ impl SortingMode {
         sorting: &mut VecDeque<(usize, ColumnSort)>,
         event: TableHeadEvent,
     ) {
-        let (i, (_, mut sort)) = sorting
+        self.update_sorting_from_event_with_options(sorting, event, &SortingOptions::default());
+    }
+
+    /// Same as [`Self::update_sorting_from_event`] but with the sort directions and the number
+    /// of sorted columns given by `options`.
+    pub fn update_sorting_from_event_with_options(
+        &self,
+        sorting: &mut VecDeque<(usize, ColumnSort)>,
+        event: TableHeadEvent,
+        options: &SortingOptions,
+    ) {
+        let position = sorting
             .iter()
-            .enumerate()
-            .find(|(_, (col_index, _))| col_index == &event.index)
-            .unwrap_or((0, &(event.index, ColumnSort::None)));
-
-        if i == 0 || sort == ColumnSort::None {
-            sort = match sort {
-                ColumnSort::None => ColumnSort::Ascending,
-                ColumnSort::Ascending => ColumnSort::Descending,
-                ColumnSort::Descending => ColumnSort::None,
-            };
+            .position(|(col_index, _)| *col_index == event.index);
+        let current_sort = position
+            .map(|position| sorting[position].1)
+            .unwrap_or(ColumnSort::None);
+
+        match self {
+            SortingMode::SingleColumn | SortingMode::MultiColumn => {
+                let mut sort = current_sort;
+
+                // a column with lower priority is first moved to the front without changing its direction
+                if position.unwrap_or_default() == 0 || sort == ColumnSort::None {
+                    sort = options.next_sort(event.index, sort);
+                }
+
+                sorting.retain(|(col_index, sort)| {
+                    *col_index != event.index && *sort != ColumnSort::None
+                });
+
+                if sort != ColumnSort::None {
+                    sorting.push_front((event.index, sort));
+                }
+
+                if self == &SortingMode::SingleColumn {
+                    sorting.truncate(1);
+                }
+            }
+            SortingMode::MultiColumnWithModifier => {
+                let sort = options.next_sort(event.index, current_sort);
+
+                if event.mouse_event.shift_key() {
+                    match position {
+                        Some(position) => sorting[position].1 = sort,
+                        None => {
+                            // make room for the new column by replacing the one with the lowest priority
+                            if let Some(max_columns) = options.max_columns {
+                                sorting.truncate(max_columns.saturating_sub(1));
+                            }
+                            sorting.push_back((event.index, sort));
+                        }
+                    }
+
+                    sorting.retain(|(_, sort)| *sort != ColumnSort::None);
+                } else {
+                    sorting.clear();
+
+                    if sort != ColumnSort::None {
+                        sorting.push_back((event.index, sort));
+                    }
+                }
+            }
+        }
+
+        if let Some(max_columns) = options.max_columns {
+            sorting.truncate(max_columns);
+        }
+    }
+}
+
+/// Options for how clicking the column headers changes the sorting. Used by the `sorting_options` prop of
+/// [`TableContent`](crate::TableContent) together with the [`SortingMode`].
+///
+/// ```
+/// # use leptos_struct_table::*;
+/// // the second column contains dates that are most interesting when newest first
+/// let options = SortingOptions::default()
+///     .first_sort(1, ColumnSort::Descending)
+///     .allow_unsorted(false)
+///     .max_columns(2);
+///
+/// assert_eq!(options.next_sort(0, ColumnSort::None), ColumnSort::Ascending);
+/// assert_eq!(options.next_sort(1, ColumnSort::None), ColumnSort::Descending);
+/// assert_eq!(options.next_sort(1, ColumnSort::Descending), ColumnSort::Ascending);
+/// assert_eq!(options.next_sort(1, ColumnSort::Ascending), ColumnSort::Descending);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SortingOptions {
+    max_columns: Option<usize>,
+    allow_unsorted: bool,
+    first_sorts: HashMap<usize, ColumnSort>,
+}
+
+impl Default for SortingOptions {
+    fn default() -> Self {
+        Self {
+            max_columns: None,
+            allow_unsorted: true,
+            first_sorts: HashMap::new(),
+        }
+    }
+}
+
+impl SortingOptions {
+    /// The maximum number of columns the table is sorted by. The columns with the lowest priority are
+    /// dropped when more are added. Defaults to unlimited.
+    pub fn max_columns(mut self, max_columns: usize) -> Self {
+        self.max_columns = Some(max_columns.max(1));
+        self
+    }
+
+    /// Whether a column goes back to unsorted after it has been sorted in both directions. If `false` it toggles
+    /// between ascending and descending. Defaults to `true`.
+    pub fn allow_unsorted(mut self, allow_unsorted: bool) -> Self {
+        self.allow_unsorted = allow_unsorted;
+        self
+    }
+
+    /// The direction the column with the given index is sorted in when it's clicked first. Defaults to
+    /// `ColumnSort::Ascending` for all columns. Descending often makes more sense for dates or scores.
+    ///
+    /// Usually this is declared with `#[table(sort_first = "desc")]` on the field (see [`TableRow::first_sort`])
+    /// which [`TableContent`](crate::TableContent) applies for the columns that aren't given here.
+    pub fn first_sort(mut self, col_index: usize, sort: ColumnSort) -> Self {
+        if sort == ColumnSort::None {
+            self.first_sorts.remove(&col_index);
+        } else {
+            self.first_sorts.insert(col_index, sort);
+        }
+        self
+    }
+
+    /// Adds the [`TableRow::first_sort`] of the columns that don't have a first sort yet.
+    pub(crate) fn with_first_sorts_of<Row: TableRow>(mut self) -> Self {
+        for col_index in 0..Row::column_count() {
+            let sort = Row::first_sort(col_index);
+            if sort != ColumnSort::Ascending {
+                self.first_sorts.entry(col_index).or_insert(sort);
+            }
         }
+        self
+    }
 
-        *sorting = sorting
-            .clone()
-            .into_iter()
-            .filter(|(col_index, sort)| *col_index != event.index && *sort != ColumnSort::None)
-            .collect();
+    /// The sort of the column with the given index after it's clicked when it's currently sorted by `sort`.
+    pub fn next_sort(&self, col_index: usize, sort: ColumnSort) -> ColumnSort {
+        let first_sort = self
+            .first_sorts
+            .get(&col_index)
+            .copied()
+            .unwrap_or(ColumnSort::Ascending);
+        let second_sort = match first_sort {
+            ColumnSort::Descending => ColumnSort::Ascending,
+            _ => ColumnSort::Descending,
+        };
 
-        if sort != ColumnSort::None {
-            sorting.push_front((event.index, sort));
+        match sort {
+            ColumnSort::None => first_sort,
+            sort if sort == first_sort => second_sort,
+            _ if self.allow_unsorted => ColumnSort::None,
+            _ => first_sort,
         }
+    }
+}
+
+/// Builds a sorting for the `sorting` or `default_sorting` prop of [`TableContent`](crate::TableContent) from
+/// column names ([`TableRow::col_name`]) instead of column indices. Names that don't exist are ignored.
+///
+/// ```
+/// # use leptos_struct_table::*;
+/// # use std::collections::VecDeque;
+/// #[derive(TableRow, Clone)]
+/// struct Book {
+///     title: String,
+///     published_at: u32,
+/// }
+///
+/// let sorting = sorting_by_name::<Book>([
+///     ("published_at", ColumnSort::Descending),
+///     ("title", ColumnSort::Ascending),
+/// ]);
+///
+/// assert_eq!(sorting, VecDeque::from([(1, ColumnSort::Descending), (0, ColumnSort::Ascending)]));
+/// ```
+pub fn sorting_by_name<Row: TableRow>(
+    sorting: impl IntoIterator<Item = (impl AsRef<str>, ColumnSort)>,
+) -> VecDeque<(usize, ColumnSort)> {
+    sorting
+        .into_iter()
+        .filter_map(|(col_name, sort)| {
+            (0..Row::column_count())
+                .find(|col_index| Row::col_name(*col_index) == col_name.as_ref())
+                .map(|col_index| (col_index, sort))
+        })
+        .collect()
+}
+
+/// Where empty values (`NULL` in SQL, `None` in Rust) are placed when a column is sorted.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum NullsOrder {
+    /// Empty values come before all other values regardless of the sort direction.
+    First,
+    /// Empty values come after all other values regardless of the sort direction.
+    Last,
+}
 
-        if self == &SortingMode::SingleColumn {
-            sorting.truncate(1);
+impl NullsOrder {
+    /// Returns `NULLS FIRST` or `NULLS LAST`.
+    pub fn as_sql(&self) -> &'static str {
+        match self {
+            NullsOrder::First => "NULLS FIRST",
+            NullsOrder::Last => "NULLS LAST",
         }
     }
 }
+
+/// The [`NullsOrder`] per column. Columns without one are sorted the default way of the database or,
+/// on the client, of the comparator.
+///
+/// The same value can be passed to [`SqlSortingOptions::nulls_ordering`](crate::SqlSortingOptions::nulls_ordering)
+/// and [`sort_rows_by_with_nulls`] so the server and the client sort the same way.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct NullsOrdering {
+    columns: HashMap<usize, NullsOrder>,
+}
+
+impl NullsOrdering {
+    /// Sets the [`NullsOrder`] of the column with the given index.
+    pub fn column(mut self, col_index: usize, nulls_order: NullsOrder) -> Self {
+        self.columns.insert(col_index, nulls_order);
+        self
+    }
+
+    /// Returns the [`NullsOrder`] of the column with the given index if one was set.
+    pub fn get(&self, col_index: usize) -> Option<NullsOrder> {
+        self.columns.get(&col_index).copied()
+    }
+}
+
+/// Sorts the rows by all sorted columns in order of priority. Rows that are equal in every sorted
+/// column keep their order, so the sort is stable.
+///
+/// `cmp` compares two rows by the column with the given index in ascending order. This lets you
+/// decide per column how rows are compared, for example by a derived key or with [`cmp_natural`](crate::cmp_natural),
+/// when you implement [`TableDataProvider::set_sorting`](crate::TableDataProvider::set_sorting) yourself
+/// instead of using `#[table(impl_vec_data_provider)]`.
+///
+/// ```
+/// # use leptos_struct_table::*;
+/// # use std::collections::VecDeque;
+/// # use std::ops::Range;
+/// #[derive(TableRow, Clone)]
+/// struct File {
+///     name: String,
+///     size: u64,
+/// }
+///
+/// struct Files(Vec<File>);
+///
+/// impl TableDataProvider<File> for Files {
+///     async fn get_rows(&self, range: Range<usize>) -> Result<(Vec<File>, Range<usize>), String> {
+///         Ok(get_vec_range_clamped(&self.0, range))
+///     }
+///
+///     async fn row_count(&self) -> Option<usize> {
+///         Some(self.0.len())
+///     }
+///
+///     fn set_sorting(&mut self, sorting: &VecDeque<(usize, ColumnSort)>) {
+///         sort_rows_by(&mut self.0, sorting, |a, b, col_index| match col_index {
+///             0 => cmp_natural_case_insensitive(&a.name, &b.name),
+///             _ => a.size.cmp(&b.size),
+///         });
+///     }
+/// }
+///
+/// let mut files = Files(vec![
+///     File { name: "img10.png".to_string(), size: 1 },
+///     File { name: "IMG2.png".to_string(), size: 2 },
+///     File { name: "img2.png".to_string(), size: 1 },
+/// ]);
+/// files.set_sorting(&VecDeque::from([(1, ColumnSort::Ascending), (0, ColumnSort::Ascending)]));
+///
+/// let names = files.0.iter().map(|file| file.name.as_str()).collect::<Vec<_>>();
+/// assert_eq!(names, vec!["img2.png", "img10.png", "IMG2.png"]);
+/// ```
+pub fn sort_rows_by<Row>(
+    rows: &mut [Row],
+    sorting: &VecDeque<(usize, ColumnSort)>,
+    cmp: impl FnMut(&Row, &Row, usize) -> Ordering,
+) {
+    sort_rows_by_with_nulls(rows, sorting, &NullsOrdering::default(), |_, _| false, cmp);
+}
+
+/// Same as [`sort_rows_by`] but the empty values of the columns in `nulls_ordering` are placed first
+/// or last regardless of the sort direction, like `NULLS FIRST` and `NULLS LAST` in SQL.
+///
+/// `is_null` returns whether the row has an empty value in the column with the given index. `cmp` is only
+/// called for the columns in `nulls_ordering` if both values are not empty.
+///
+/// ```
+/// # use leptos_struct_table::*;
+/// # use std::collections::VecDeque;
+/// let mut rows = vec![Some(2), None, Some(1)];
+/// let nulls_ordering = NullsOrdering::default().column(0, NullsOrder::Last);
+///
+/// sort_rows_by_with_nulls(
+///     &mut rows,
+///     &VecDeque::from([(0, ColumnSort::Descending)]),
+///     &nulls_ordering,
+///     |row, _| row.is_none(),
+///     |a, b, _| a.cmp(b),
+/// );
+///
+/// assert_eq!(rows, vec![Some(2), Some(1), None]);
+/// ```
+pub fn sort_rows_by_with_nulls<Row>(
+    rows: &mut [Row],
+    sorting: &VecDeque<(usize, ColumnSort)>,
+    nulls_ordering: &NullsOrdering,
+    mut is_null: impl FnMut(&Row, usize) -> bool,
+    mut cmp: impl FnMut(&Row, &Row, usize) -> Ordering,
+) {
+    if sorting.iter().all(|(_, sort)| *sort == ColumnSort::None) {
+        return;
+    }
+
+    rows.sort_by(|a, b| {
+        for (col_index, sort) in sorting {
+            if *sort == ColumnSort::None {
+                continue;
+            }
+
+            if let Some(nulls_order) = nulls_ordering.get(*col_index) {
+                let null_ordering = match (is_null(a, *col_index), is_null(b, *col_index)) {
+                    (true, true) => continue,
+                    (true, false) => Ordering::Less,
+                    (false, true) => Ordering::Greater,
+                    (false, false) => Ordering::Equal,
+                };
+
+                match nulls_order {
+                    _ if null_ordering == Ordering::Equal => {}
+                    NullsOrder::First => return null_ordering,
+                    NullsOrder::Last => return null_ordering.reverse(),
+                }
+            }
+
+            let ordering = match sort {
+                ColumnSort::Ascending => cmp(a, b, *col_index),
+                ColumnSort::Descending => cmp(b, a, *col_index),
+                ColumnSort::None => Ordering::Equal,
+            };
+
+            if ordering != Ordering::Equal {
+                return ordering;
+            }
+        }
+
+        Ordering::Equal
+    });
+}
@@ -0,0 +1,183 @@
+use crate::NullsOrdering;
+use std::collections::HashMap;
+
+/// The SQL dialect that [`SqlSortingOptions`] quotes identifiers for.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SqlDialect {
+    /// Quotes identifiers with double quotes.
+    Postgres,
+    /// Quotes identifiers with backticks. `NULLS FIRST` and `NULLS LAST` are emulated because MySQL lacks them.
+    MySql,
+    /// Quotes identifiers with double quotes.
+    Sqlite,
+}
+
+impl SqlDialect {
+    /// Quotes the identifier. Qualified identifiers like `c.first_name` are quoted part by part.
+    ///
+    /// ```
+    /// # use leptos_struct_table::SqlDialect;
+    /// assert_eq!(SqlDialect::Postgres.quote_identifier("c.first_name"), r#""c"."first_name""#);
+    /// assert_eq!(SqlDialect::MySql.quote_identifier("order"), "`order`");
+    /// ```
+    pub fn quote_identifier(&self, identifier: &str) -> String {
+        let quote = match self {
+            SqlDialect::Postgres | SqlDialect::Sqlite => '"',
+            SqlDialect::MySql => '`',
+        };
+
+        identifier
+            .split('.')
+            .map(|part| {
+                let escaped = part.replace(quote, &format!("{quote}{quote}"));
+                format!("{quote}{escaped}{quote}")
+            })
+            .collect::<Vec<_>>()
+            .join(".")
+    }
+
+    /// Whether the dialect supports `NULLS FIRST` and `NULLS LAST`.
+    pub fn supports_nulls_order(&self) -> bool {
+        !matches!(self, SqlDialect::MySql)
+    }
+}
+
+/// Options for [`TableRow::sorting_to_sql_with_options`](crate::TableRow::sorting_to_sql_with_options): the placement
+/// of `NULL` values, the SQL dialect to quote identifiers for and the database columns that differ from the field names.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_struct_table::*;
+/// # use std::collections::VecDeque;
+/// #[derive(TableRow, Clone)]
+/// struct Task {
+///     title: String,
+///     due_at: Option<u64>,
+/// }
+///
+/// let sorting = VecDeque::from([(1, ColumnSort::Ascending), (0, ColumnSort::Ascending)]);
+/// let options = SqlSortingOptions::default()
+///     .nulls_ordering(NullsOrdering::default().column(1, NullsOrder::Last));
+///
+/// assert_eq!(
+///     Task::sorting_to_sql_with_options(&sorting, &options),
+///     Some("ORDER BY due_at ASC NULLS LAST, title ASC".to_string()),
+/// );
+///
+/// // for databases without `NULLS FIRST` and `NULLS LAST`
+/// let options = options.emulate_nulls_order(true);
+///
+/// assert_eq!(
+///     Task::sorting_to_sql_with_options(&sorting, &options),
+///     Some("ORDER BY due_at IS NULL ASC, due_at ASC, title ASC".to_string()),
+/// );
+///
+/// // MySQL has no `NULLS LAST` so it's emulated unless turned off explicitly
+/// let options = SqlSortingOptions::default()
+///     .dialect(SqlDialect::MySql)
+///     .nulls_ordering(NullsOrdering::default().column(1, NullsOrder::Last));
+///
+/// assert_eq!(
+///     Task::sorting_to_sql_with_options(&sorting, &options),
+///     Some("ORDER BY `due_at` IS NULL ASC, `due_at` ASC, `title` ASC".to_string()),
+/// );
+/// assert_eq!(
+///     Task::sorting_to_sql_with_options(&sorting, &options.emulate_nulls_order(false)),
+///     Some("ORDER BY `due_at` ASC NULLS LAST, `title` ASC".to_string()),
+/// );
+///
+/// // the columns are named differently in the database and on a joined table
+/// let options = SqlSortingOptions::default()
+///     .dialect(SqlDialect::Postgres)
+///     .db_column(0, "t.name")
+///     .sort_expr(1, "coalesce(t.due_at, t.created_at)");
+///
+/// assert_eq!(
+///     Task::sorting_to_sql_with_options(&sorting, &options),
+///     Some(r#"ORDER BY coalesce(t.due_at, t.created_at) ASC, "t"."name" ASC"#.to_string()),
+/// );
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct SqlSortingOptions {
+    pub(crate) nulls_ordering: NullsOrdering,
+    emulate_nulls_order: Option<bool>,
+    dialect: Option<SqlDialect>,
+    db_columns: HashMap<usize, String>,
+    sort_exprs: HashMap<usize, String>,
+}
+
+impl SqlSortingOptions {
+    /// Places the `NULL` values of the given columns first or last.
+    pub fn nulls_ordering(mut self, nulls_ordering: NullsOrdering) -> Self {
+        self.nulls_ordering = nulls_ordering;
+        self
+    }
+
+    /// Emulates `NULLS FIRST` and `NULLS LAST` by sorting by `column IS NULL` first. Use this
+    /// for databases that don't support them. Defaults to `true` for [`SqlDialect::MySql`] and `false`
+    /// otherwise. A value given here overrides the default of the dialect.
+    pub fn emulate_nulls_order(mut self, emulate_nulls_order: bool) -> Self {
+        self.emulate_nulls_order = Some(emulate_nulls_order);
+        self
+    }
+
+    /// Quotes the column identifiers for the given dialect. By default they are not quoted.
+    pub fn dialect(mut self, dialect: SqlDialect) -> Self {
+        self.dialect = Some(dialect);
+        self
+    }
+
+    /// The database column of the column with the given index if it differs from the field name.
+    /// It can be qualified with the table like `c.first_name` and is quoted according to the dialect.
+    ///
+    /// Usually this is declared with `#[table(db_column = "...")]` on the field (see
+    /// [`TableRow::db_column`](crate::TableRow::db_column)). This overrides it at runtime.
+    pub fn db_column(mut self, col_index: usize, db_column: impl Into<String>) -> Self {
+        self.db_columns.insert(col_index, db_column.into());
+        self
+    }
+
+    /// A raw SQL expression to sort the column with the given index by, like `lower(name)`.
+    /// It is inserted as is without any quoting so never pass user input here.
+    ///
+    /// Usually this is declared with `#[table(sort_expr = "...")]` on the field (see
+    /// [`TableRow::sort_expr`](crate::TableRow::sort_expr)). This overrides it at runtime.
+    pub fn sort_expr(mut self, col_index: usize, sort_expr: impl Into<String>) -> Self {
+        self.sort_exprs.insert(col_index, sort_expr.into());
+        self
+    }
+
+    /// The SQL that the column with the given index is sorted by. `db_column` and `sort_expr` are the ones of the row.
+    pub(crate) fn column_sql(
+        &self,
+        col_index: usize,
+        db_column: &str,
+        sort_expr: Option<&str>,
+    ) -> String {
+        if let Some(sort_expr) = self.sort_exprs.get(&col_index) {
+            return sort_expr.clone();
+        }
+
+        // a database column given here overrides the sort expression of the row
+        let db_column = match self.db_columns.get(&col_index) {
+            Some(db_column) => db_column,
+            None => match sort_expr {
+                Some(sort_expr) => return sort_expr.to_string(),
+                None => db_column,
+            },
+        };
+
+        match self.dialect {
+            Some(dialect) => dialect.quote_identifier(db_column),
+            None => db_column.to_string(),
+        }
+    }
+
+    /// Whether `NULLS FIRST` and `NULLS LAST` have to be emulated.
+    pub(crate) fn emulates_nulls_order(&self) -> bool {
+        self.emulate_nulls_order.unwrap_or_else(|| {
+            self.dialect
+                .is_some_and(|dialect| !dialect.supports_nulls_order())
+        })
+    }
+}
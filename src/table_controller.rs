@@ -0,0 +1,200 @@
+use crate::loaded_rows::RowState;
+use crate::table_row::TableRow;
+use crate::ColumnSort;
+use leptos::attr::{aria_colcount, aria_multiselectable, aria_rowcount, role, Attribute};
+use leptos::prelude::*;
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::ops::Range;
+use std::rc::Rc;
+
+/// Where the row is placed in the scroll container by [`TableController::scroll_to_index`].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum ScrollAlign {
+    /// The row is aligned with the top of the scroll container.
+    #[default]
+    Start,
+    /// The row is centered in the scroll container.
+    Center,
+    /// The row is aligned with the bottom of the scroll container.
+    End,
+    /// The container is only scrolled if the row isn't visible. Then it's aligned with the closest edge.
+    Nearest,
+}
+
+impl ScrollAlign {
+    pub(crate) fn as_scroll_logical_position(&self) -> web_sys::ScrollLogicalPosition {
+        match self {
+            ScrollAlign::Start => web_sys::ScrollLogicalPosition::Start,
+            ScrollAlign::Center => web_sys::ScrollLogicalPosition::Center,
+            ScrollAlign::End => web_sys::ScrollLogicalPosition::End,
+            ScrollAlign::Nearest => web_sys::ScrollLogicalPosition::Nearest,
+        }
+    }
+}
+
+/// You can pass this to a [`TableContent`](crate::TableContent) component's `controller` prop to scroll to rows
+/// programmatically and to read which rows are visible.
+///
+/// This works with every [`DisplayStrategy`](crate::DisplayStrategy). With pagination the page of the row is shown first.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_struct_table::*;
+/// # #[derive(TableRow, Clone)]
+/// # struct Book {
+/// #     id: u32,
+/// #     title: String,
+/// # }
+/// # fn demo() {
+/// let controller = TableController::<Book>::default();
+///
+/// // go to the newly created book
+/// controller.scroll_to_key(42, |book| book.id, ScrollAlign::Center);
+///
+/// Effect::new(move || {
+///     leptos::logging::log!("visible rows: {:?}", controller.visible_range().get());
+/// });
+/// # }
+/// ```
+pub struct TableController<Row: Send + Sync + 'static> {
+    pub(crate) scroll_request: RwSignal<Option<(usize, ScrollAlign)>>,
+    /// The sorting signal of the table together with its default sorting.
+    pub(crate) sorting: StoredValue<Option<(RwSignal<Sorting>, Sorting)>>,
+    pub(crate) visible_range_signal: RwSignal<Range<usize>>,
+    pub(crate) row_count_signal: RwSignal<Option<usize>>,
+    pub(crate) multiselectable: RwSignal<bool>,
+    pub(crate) get_loaded_rows: ControllerRowsGetter<Row>,
+}
+
+type Sorting = VecDeque<(usize, ColumnSort)>;
+
+pub(crate) type ControllerRowsGetter<Row> = Rc<RefCell<Box<dyn Fn() -> Vec<RowState<Row>>>>>;
+
+impl<Row: Send + Sync + 'static> Clone for TableController<Row> {
+    fn clone(&self) -> Self {
+        Self {
+            scroll_request: self.scroll_request,
+            sorting: self.sorting,
+            visible_range_signal: self.visible_range_signal,
+            row_count_signal: self.row_count_signal,
+            multiselectable: self.multiselectable,
+            get_loaded_rows: Rc::clone(&self.get_loaded_rows),
+        }
+    }
+}
+
+impl<Row: Send + Sync + 'static> Default for TableController<Row> {
+    fn default() -> Self {
+        Self {
+            scroll_request: RwSignal::new(None),
+            sorting: StoredValue::new(None),
+            visible_range_signal: RwSignal::new(0..0),
+            row_count_signal: RwSignal::new(None),
+            multiselectable: RwSignal::new(false),
+            get_loaded_rows: Rc::new(RefCell::new(Box::new(Vec::new))),
+        }
+    }
+}
+
+impl<Row: Send + Sync + 'static> TableController<Row> {
+    /// Scrolls to the row with the given index. Rows that are not loaded yet are loaded first.
+    pub fn scroll_to_index(&self, index: usize, align: ScrollAlign) {
+        self.scroll_request.set(Some((index, align)));
+    }
+
+    /// Scrolls to the first loaded row whose key as returned by `row_key` equals `key`.
+    ///
+    /// Only rows that have been loaded already are searched. Returns the index of the row if one was found.
+    pub fn scroll_to_key<K: PartialEq>(
+        &self,
+        key: K,
+        row_key: impl Fn(&Row) -> K,
+        align: ScrollAlign,
+    ) -> Option<usize> {
+        let loaded_rows = (*self.get_loaded_rows.borrow())();
+
+        let index = loaded_rows.into_iter().position(|row| match row {
+            RowState::Loaded(row) => row.with_untracked(|row| row_key(row) == key),
+            _ => false,
+        })?;
+
+        self.scroll_to_index(index, align);
+
+        Some(index)
+    }
+
+    /// Sets the sorting back to the `default_sorting` of the [`TableContent`](crate::TableContent) which defaults
+    /// to [`TableRow::default_sorting`]. Does nothing before the table is rendered.
+    pub fn reset_sorting(&self) {
+        self.sorting.with_value(|sorting| {
+            if let Some((sorting, default_sorting)) = sorting {
+                sorting.set(default_sorting.clone());
+            }
+        });
+    }
+
+    /// Returns a `Signal` of the range of row indices that are currently visible in the scroll container.
+    pub fn visible_range(&self) -> Signal<Range<usize>> {
+        self.visible_range_signal.into()
+    }
+
+    /// Returns a `Signal` of the index of the first row that is currently visible in the scroll container.
+    pub fn first_visible_row(&self) -> Signal<usize> {
+        let visible_range = self.visible_range_signal;
+        Signal::derive(move || visible_range.read().start)
+    }
+
+    /// Returns a `Signal` of the total number of rows once the data provider reports it.
+    pub fn row_count(&self) -> Signal<Option<usize>> {
+        self.row_count_signal.into()
+    }
+}
+
+impl<Row: TableRow + Send + Sync + 'static> TableController<Row> {
+    /// The ARIA grid attributes of the table element: `role="grid"`, `aria-rowcount`, `aria-colcount`
+    /// and `aria-multiselectable`. The table element is rendered by you, so spread them onto it if it
+    /// is an HTML `<table>`.
+    ///
+    /// ```
+    /// # use leptos::prelude::*;
+    /// # use leptos_struct_table::*;
+    /// # #[derive(TableRow, Clone)]
+    /// # #[table(impl_vec_data_provider)]
+    /// # struct Book {
+    /// #     title: String,
+    /// # }
+    /// # fn demo(rows: Vec<Book>) -> impl IntoView {
+    /// let controller = TableController::<Book>::default();
+    /// let grid_attributes = controller.grid_attributes();
+    ///
+    /// view! {
+    ///     <table {..grid_attributes}>
+    ///         <TableContent
+    ///             rows
+    ///             controller
+    ///             live_region_renderer=DefaultLiveRegionRenderer
+    ///             scroll_container="html"
+    ///         />
+    ///     </table>
+    /// }
+    /// # }
+    /// ```
+    pub fn grid_attributes(&self) -> impl Attribute {
+        let row_count = self.row_count_signal;
+        let multiselectable = self.multiselectable;
+
+        (
+            role("grid"),
+            // the head row is counted as well
+            aria_rowcount(move || {
+                row_count
+                    .get()
+                    .map(|row_count| (row_count + 1).to_string())
+                    .unwrap_or_else(|| "-1".to_string())
+            }),
+            aria_colcount(move || Row::column_count().to_string()),
+            aria_multiselectable(move || multiselectable.get().then_some("true")),
+        )
+    }
+}
@@ -1,4 +1,7 @@
-use crate::{ColumnSort, TableClassesProvider, TableHeadEvent};
+use crate::{
+    CellParseError, ColumnSort, ExportValue, NullsOrder, NullsOrdering, SqlSortingOptions,
+    TableClassesProvider, TableHeadEvent,
+};
 use leptos::prelude::*;
 use std::collections::VecDeque;
 
@@ -13,6 +16,12 @@ pub trait TableRow: Sized {
     /// How many columns this row has (i.e. the number of fields in the struct)
     const COLUMN_COUNT: usize;
 
+    /// How many columns this row has at runtime. Defaults to [`Self::COLUMN_COUNT`].
+    /// Only rows whose columns are defined at runtime like [`DynamicRow`](crate::DynamicRow) override this.
+    fn column_count() -> usize {
+        Self::COLUMN_COUNT
+    }
+
     /// Renders the inner of one row of the table using the cell renderers.
     /// This produces the children that go into the `row_renderer` given to [`TableContent`].
     ///
@@ -54,15 +63,197 @@ pub trait TableRow: Sized {
     /// ```
     fn col_name(col_index: usize) -> &'static str;
 
+    /// The title of the column at the given index as given by `#[table(title = "...")]`. Defaults to the column name
+    /// in title case (`this_field` becomes `"This Field"`). This is used by exporters and the clipboard support.
+    ///
+    /// ```
+    /// # use leptos_struct_table::*;
+    /// # use leptos::prelude::*;
+    /// #
+    /// #[derive(TableRow)]
+    /// struct Book {
+    ///     #[table(title = "Book title")]
+    ///     title: String,
+    ///     published_at: u16,
+    /// }
+    ///
+    /// assert_eq!(Book::col_title(0), "Book title");
+    /// assert_eq!(Book::col_title(1), "Published At");
+    /// ```
+    fn col_title(col_index: usize) -> String {
+        title_case(Self::col_name(col_index))
+    }
+
+    /// The direction the column at the given index is sorted in when its header is clicked first as given by
+    /// `#[table(sort_first = "desc")]`. Defaults to `ColumnSort::Ascending`.
+    /// [`SortingOptions::first_sort`](crate::SortingOptions::first_sort) overrides this.
+    ///
+    /// ```
+    /// # use leptos_struct_table::*;
+    /// # use leptos::prelude::*;
+    /// #
+    /// #[derive(TableRow)]
+    /// #[table(sortable)]
+    /// struct Article {
+    ///     title: String,
+    ///     #[table(sort_first = "desc")]
+    ///     published_at: u32,
+    /// }
+    ///
+    /// assert_eq!(Article::first_sort(0), ColumnSort::Ascending);
+    /// assert_eq!(Article::first_sort(1), ColumnSort::Descending);
+    /// ```
+    #[allow(unused_variables)]
+    fn first_sort(col_index: usize) -> ColumnSort {
+        ColumnSort::Ascending
+    }
+
+    /// The sorting the table starts with if no `sorting` is given to [`TableContent`](crate::TableContent) and that
+    /// [`TableController::reset_sorting`](crate::TableController::reset_sorting) restores. It's declared with
+    /// `#[table(default_sort = "asc")]` or `"desc"` on the fields. If more than one field has a default sort,
+    /// `#[table(default_sort_priority = 0)]` orders them with the lowest number first. Fields without a priority
+    /// come last in the order of the struct. Defaults to unsorted.
+    ///
+    /// ```
+    /// # use leptos_struct_table::*;
+    /// # use leptos::prelude::*;
+    /// # use std::collections::VecDeque;
+    /// #
+    /// #[derive(TableRow)]
+    /// #[table(sortable)]
+    /// struct Article {
+    ///     #[table(default_sort = "asc", default_sort_priority = 1)]
+    ///     title: String,
+    ///     #[table(default_sort = "desc", default_sort_priority = 0)]
+    ///     published_at: u32,
+    /// }
+    ///
+    /// assert_eq!(
+    ///     Article::default_sorting(),
+    ///     VecDeque::from([(1, ColumnSort::Descending), (0, ColumnSort::Ascending)]),
+    /// );
+    /// ```
+    fn default_sorting() -> VecDeque<(usize, ColumnSort)> {
+        VecDeque::new()
+    }
+
+    /// Where the `None` values of the columns are placed when they are sorted as given by `#[table(nulls = "first")]`
+    /// or `"last"` on `Option` fields. It is applied by the data provider of `#[table(impl_vec_data_provider)]`,
+    /// [`Self::sorting_to_sql`] and the query builder integrations so the client and the database sort the same way.
+    /// Defaults to none.
+    ///
+    /// ```
+    /// # use leptos_struct_table::*;
+    /// # use leptos::prelude::*;
+    /// # use std::collections::VecDeque;
+    /// #
+    /// #[derive(TableRow, Clone)]
+    /// #[table(sortable, impl_vec_data_provider)]
+    /// struct Task {
+    ///     name: String,
+    ///     #[table(nulls = "last")]
+    ///     due_at: Option<u32>,
+    /// }
+    ///
+    /// let sorting = VecDeque::from([(1, ColumnSort::Descending)]);
+    /// assert_eq!(Task::nulls_ordering().get(1), Some(NullsOrder::Last));
+    /// assert_eq!(
+    ///     Task::sorting_to_sql(&sorting),
+    ///     Some("ORDER BY due_at DESC NULLS LAST".to_string()),
+    /// );
+    ///
+    /// let task = |due_at| Task { name: String::new(), due_at };
+    /// let mut tasks = vec![task(Some(1)), task(None), task(Some(2))];
+    /// tasks.set_sorting(&sorting);
+    /// assert_eq!(tasks.iter().map(|task| task.due_at).collect::<Vec<_>>(), [Some(2), Some(1), None]);
+    /// ```
+    fn nulls_ordering() -> NullsOrdering {
+        NullsOrdering::default()
+    }
+
+    /// The database column of the column at the given index as given by `#[table(db_column = "c.first_name")]`.
+    /// It can be qualified with a table and is quoted by [`Self::sorting_to_sql_with_options`] according to the
+    /// [`SqlDialect`](crate::SqlDialect). Defaults to [`Self::col_name`].
+    ///
+    /// ```
+    /// # use leptos_struct_table::*;
+    /// # use leptos::prelude::*;
+    /// # use std::collections::VecDeque;
+    /// #
+    /// #[derive(TableRow)]
+    /// #[table(sortable)]
+    /// struct Customer {
+    ///     #[table(db_column = "c.first_name")]
+    ///     name: String,
+    ///     order: u32,
+    ///     #[table(sort_expr = "coalesce(c.last_login_at, c.created_at)")]
+    ///     last_seen: String,
+    /// }
+    ///
+    /// let sorting = VecDeque::from([(0, ColumnSort::Ascending), (1, ColumnSort::Ascending), (2, ColumnSort::Descending)]);
+    /// let options = SqlSortingOptions::default().dialect(SqlDialect::Postgres);
+    ///
+    /// assert_eq!(
+    ///     Customer::sorting_to_sql_with_options(&sorting, &options),
+    ///     Some(r#"ORDER BY "c"."first_name" ASC, "order" ASC, coalesce(c.last_login_at, c.created_at) DESC"#.to_string()),
+    /// );
+    /// ```
+    fn db_column(col_index: usize) -> &'static str {
+        Self::col_name(col_index)
+    }
+
+    /// The raw SQL expression the column at the given index is sorted by as given by
+    /// `#[table(sort_expr = "lower(name)")]`. It is inserted into the SQL as is. Defaults to `None` which
+    /// sorts by [`Self::db_column`].
+    #[allow(unused_variables)]
+    fn sort_expr(col_index: usize) -> Option<&'static str> {
+        None
+    }
+
     /// Converts the given sorting to an SQL statement.
     /// Return `None` when there is nothing to be sorted otherwise `Some("ORDER BY ...")`.
-    /// Uses [`Self::col_name`] to get the column names for sorting.
+    /// Uses [`Self::db_column`] and [`Self::sort_expr`] to get the columns for sorting.
     fn sorting_to_sql(sorting: &VecDeque<(usize, ColumnSort)>) -> Option<String> {
+        Self::sorting_to_sql_with_options(sorting, &SqlSortingOptions::default())
+    }
+
+    /// Same as [`Self::sorting_to_sql`] but with the placement of `NULL` values, the quoting of identifiers and
+    /// the database columns given by `options`. Please see [`SqlSortingOptions`] for an example.
+    /// Columns without a `NULL` placement in `options` use the one of [`Self::nulls_ordering`].
+    fn sorting_to_sql_with_options(
+        sorting: &VecDeque<(usize, ColumnSort)>,
+        options: &SqlSortingOptions,
+    ) -> Option<String> {
         let mut sort = vec![];
+        let nulls_ordering = Self::nulls_ordering();
 
         for (col, col_sort) in sorting {
-            if let Some(col_sort) = col_sort.as_sql() {
-                sort.push(format!("{} {}", Self::col_name(*col), col_sort))
+            let Some(col_sort) = col_sort.as_sql() else {
+                continue;
+            };
+            let col_name = options.column_sql(*col, Self::db_column(*col), Self::sort_expr(*col));
+
+            match options
+                .nulls_ordering
+                .get(*col)
+                .or_else(|| nulls_ordering.get(*col))
+            {
+                None => sort.push(format!("{} {}", col_name, col_sort)),
+                Some(nulls_order) if options.emulates_nulls_order() => {
+                    // `false` sorts before `true`
+                    let nulls_sort = match nulls_order {
+                        NullsOrder::First => "DESC",
+                        NullsOrder::Last => "ASC",
+                    };
+                    sort.push(format!("{} IS NULL {}", col_name, nulls_sort));
+                    sort.push(format!("{} {}", col_name, col_sort));
+                }
+                Some(nulls_order) => sort.push(format!(
+                    "{} {} {}",
+                    col_name,
+                    col_sort,
+                    nulls_order.as_sql()
+                )),
             }
         }
 
@@ -74,6 +265,108 @@ pub trait TableRow: Sized {
     }
 }
 
+/// Plain-text access to the columns of a row. This is used by exporters like [`CsvExporter`](crate::CsvExporter)
+/// and the clipboard support which work with text instead of the rendered views.
+///
+/// Add `#[table(impl_row_text)]` to let `#[derive(TableRow)]` implement it with the same format options
+/// as the table. This requires [`CellText`](crate::CellText) for the types of all columns.
+///
+/// ```
+/// # use leptos_struct_table::*;
+/// # use leptos::prelude::*;
+/// #
+/// #[derive(TableRow, Clone)]
+/// #[table(impl_row_text)]
+/// struct Book {
+///     title: String,
+///     #[table(format(precision = 2usize))]
+///     price: f64,
+///     #[table(none_value = "-")]
+///     isbn: Option<String>,
+/// }
+///
+/// let book = Book { title: "Dune".to_string(), price: 9.5, isbn: None };
+///
+/// assert_eq!(book.cell_text(1), "9.50");
+/// assert_eq!(book.cell_text(2), "-");
+/// assert_eq!(
+///     book.export_value(1),
+///     ExportValue::Number { value: 9.5, precision: Some(2) }
+/// );
+/// ```
+///
+/// Columns of types without [`CellText`](crate::CellText) require implementing it by hand.
+pub trait TableRowText: TableRow {
+    /// The content of the cell at the given column index as plain text.
+    /// Use [`CellText::render_text`](crate::CellText::render_text) to apply the same format options as in the table.
+    fn cell_text(&self, col_index: usize) -> String;
+
+    /// The typed content of the cell at the given column index for exporters that support
+    /// more than plain text. Defaults to the text returned by [`Self::cell_text`].
+    /// Use [`CellText::export_value`](crate::CellText::export_value) to implement this.
+    fn export_value(&self, col_index: usize) -> ExportValue {
+        ExportValue::Text(self.cell_text(col_index))
+    }
+
+    /// Sets the cell at the given column index from plain text, e.g. when pasting from a spreadsheet
+    /// with [`TableClipboard`](crate::TableClipboard). Use [`ParseCellValue`](crate::ParseCellValue)
+    /// to implement this and only assign the field if parsing succeeded.
+    ///
+    /// Defaults to [`CellParseError::NotEditable`] for every column.
+    ///
+    /// ```
+    /// # use leptos_struct_table::*;
+    /// # use leptos::prelude::*;
+    /// #
+    /// #[derive(TableRow, Clone)]
+    /// struct Book {
+    ///     title: String,
+    ///     pages: u32,
+    /// }
+    ///
+    /// impl TableRowText for Book {
+    ///     fn cell_text(&self, col_index: usize) -> String {
+    ///         match col_index {
+    ///             0 => self.title.clone(),
+    ///             _ => self.pages.to_string(),
+    ///         }
+    ///     }
+    ///
+    ///     fn set_cell_text(&mut self, col_index: usize, text: &str) -> Result<(), CellParseError> {
+    ///         match col_index {
+    ///             0 => self.title = String::parse_cell_value(text)?,
+    ///             1 => self.pages = u32::parse_cell_value(text)?,
+    ///             _ => return Err(CellParseError::NotEditable),
+    ///         }
+    ///         Ok(())
+    ///     }
+    /// }
+    ///
+    /// let mut book = Book { title: "Dune".to_string(), pages: 412 };
+    /// assert!(book.set_cell_text(1, "four hundred").is_err());
+    /// assert_eq!(book.set_cell_text(1, "896"), Ok(()));
+    /// assert_eq!(book.pages, 896);
+    /// ```
+    #[allow(unused_variables)]
+    fn set_cell_text(&mut self, col_index: usize, text: &str) -> Result<(), CellParseError> {
+        Err(CellParseError::NotEditable)
+    }
+}
+
+pub(crate) fn title_case(name: &str) -> String {
+    name.split(['_', ' '])
+        .filter(|word| !word.is_empty())
+        .map(|word| {
+            let mut chars = word.chars();
+            chars
+                .next()
+                .map(|first| first.to_uppercase().chain(chars).collect::<String>())
+                .unwrap_or_default()
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
 pub fn get_sorting_for_column(
     col_index: usize,
     sorting: Signal<VecDeque<(usize, ColumnSort)>>,
@@ -0,0 +1,265 @@
+use crate::{sorting_by_name, ColumnSort, PaginationController, TableRow};
+use codee::string::JsonSerdeCodec;
+use leptos::prelude::*;
+use leptos_use::storage::use_local_storage;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, VecDeque};
+
+/// A serializable snapshot of the state of a table that the user can adjust.
+///
+/// Columns are referenced by their name ([`TableRow::col_name`]) so the state stays valid when columns are
+/// reordered in the struct. [`use_persisted_table_state`] saves it to the local storage.
+///
+/// The sorting and the current page are applied to the table by [`use_persisted_table_state`]. Column widths, order
+/// and visibility are stored for you to apply to your columns, for example with [`DynamicColumns`](crate::DynamicColumns).
+///
+/// ```
+/// # use leptos_struct_table::*;
+/// # use std::collections::VecDeque;
+/// # #[derive(TableRow, Clone)]
+/// # struct Book {
+/// #     title: String,
+/// #     published_at: u32,
+/// # }
+/// let mut state = TableState::default();
+/// state.set_sorting::<Book>(&VecDeque::from([(1, ColumnSort::Descending)]));
+///
+/// assert_eq!(state.sorting, vec![("published_at".to_string(), ColumnSort::Descending)]);
+/// assert_eq!(state.sorting_for::<Book>(), VecDeque::from([(1, ColumnSort::Descending)]));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TableState {
+    /// The sorted columns by name in order of priority.
+    pub sorting: Vec<(String, ColumnSort)>,
+    /// The current page if the table is paginated. The first page is `0`.
+    pub current_page: usize,
+    /// The number of rows per page if the table is paginated.
+    pub page_size: Option<usize>,
+    /// The names of the columns in the order they are displayed. Empty if not changed by the user.
+    pub column_order: Vec<String>,
+    /// The names of the columns that are hidden.
+    pub hidden_columns: Vec<String>,
+    /// The widths of the columns in pixels by name.
+    pub column_widths: BTreeMap<String, f64>,
+}
+
+impl TableState {
+    /// Returns the sorting by column index like the `sorting` prop of [`TableContent`](crate::TableContent).
+    /// Columns that don't exist (anymore) are ignored.
+    pub fn sorting_for<Row: TableRow>(&self) -> VecDeque<(usize, ColumnSort)> {
+        sorting_by_name::<Row>(
+            self.sorting
+                .iter()
+                .map(|(col_name, sort)| (col_name, *sort)),
+        )
+    }
+
+    /// Stores the sorting given by column index like the `sorting` prop of [`TableContent`](crate::TableContent).
+    pub fn set_sorting<Row: TableRow>(&mut self, sorting: &VecDeque<(usize, ColumnSort)>) {
+        self.sorting = sorting
+            .iter()
+            .filter(|(_, sort)| *sort != ColumnSort::None)
+            .map(|(col_index, sort)| (Row::col_name(*col_index).to_string(), *sort))
+            .collect();
+    }
+}
+
+/// The [`TableState`] as it is saved in the storage.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+struct PersistedTableState {
+    version: u32,
+    columns: Vec<String>,
+    state: TableState,
+}
+
+/// Options for [`use_persisted_table_state`].
+#[derive(Clone, Default)]
+pub struct UsePersistedTableStateOptions {
+    version: u32,
+    sorting: Option<RwSignal<VecDeque<(usize, ColumnSort)>>>,
+    pagination: Option<PaginationController>,
+}
+
+impl UsePersistedTableStateOptions {
+    /// The version of the saved state. Increase it when the meaning of the saved state changes to drop
+    /// the state that users have saved before. Defaults to `0`.
+    pub fn version(mut self, version: u32) -> Self {
+        self.version = version;
+        self
+    }
+
+    /// Restores and saves the sorting of the table.
+    pub fn sorting(mut self, sorting: RwSignal<VecDeque<(usize, ColumnSort)>>) -> Self {
+        self.sorting = Some(sorting);
+        self
+    }
+
+    /// Restores and saves the current page and the page size of the table.
+    pub fn pagination(mut self, controller: PaginationController) -> Self {
+        self.pagination = Some(controller);
+        self
+    }
+
+    /// Applies the saved state to the sorting and pagination. If there is no saved state they are left untouched
+    /// and the returned state is read from them.
+    ///
+    /// [`use_persisted_table_state`] calls this with the state from the local storage. Call it yourself if you
+    /// keep the state somewhere else, for example on your server.
+    ///
+    /// ```
+    /// # use leptos::prelude::*;
+    /// # use leptos_struct_table::*;
+    /// # use std::collections::VecDeque;
+    /// # #[derive(TableRow, Clone)]
+    /// # struct Book {
+    /// #     title: String,
+    /// #     published_at: u32,
+    /// # }
+    /// let sorting = RwSignal::new(VecDeque::from([(0, ColumnSort::Descending)]));
+    /// let options = UsePersistedTableStateOptions::default().sorting(sorting);
+    ///
+    /// // no saved state keeps the initial sorting
+    /// let state = options.restore::<Book>(None);
+    /// assert_eq!(sorting.get_untracked(), VecDeque::from([(0, ColumnSort::Descending)]));
+    /// assert_eq!(state.sorting, vec![("title".to_string(), ColumnSort::Descending)]);
+    ///
+    /// let mut saved_state = TableState::default();
+    /// saved_state.set_sorting::<Book>(&VecDeque::from([(1, ColumnSort::Ascending)]));
+    ///
+    /// options.restore::<Book>(Some(saved_state));
+    /// assert_eq!(sorting.get_untracked(), VecDeque::from([(1, ColumnSort::Ascending)]));
+    /// ```
+    pub fn restore<Row: TableRow>(&self, saved_state: Option<TableState>) -> TableState {
+        match saved_state {
+            Some(saved_state) => {
+                if let Some(sorting) = self.sorting {
+                    sorting.set(saved_state.sorting_for::<Row>());
+                }
+                if let Some(pagination) = self.pagination {
+                    if let Some(page_size) = saved_state.page_size {
+                        pagination.set_page_size(page_size);
+                    }
+                    pagination.current_page.set(saved_state.current_page);
+                }
+
+                saved_state
+            }
+            None => {
+                let mut state = TableState::default();
+                if let Some(sorting) = self.sorting {
+                    state.set_sorting::<Row>(&sorting.read_untracked());
+                }
+                if let Some(pagination) = self.pagination {
+                    state.current_page = pagination.current_page.get_untracked();
+                    state.page_size = pagination.page_size().get_untracked();
+                }
+
+                state
+            }
+        }
+    }
+}
+
+/// Return type of [`use_persisted_table_state`].
+pub struct UsePersistedTableStateReturn<RemoveFn>
+where
+    RemoveFn: Fn() + Clone + Send + Sync,
+{
+    /// The current state. Changes are saved to the local storage.
+    pub state: RwSignal<TableState>,
+    /// Removes the saved state from the local storage.
+    pub clear: RemoveFn,
+}
+
+/// Saves the [`TableState`] of the table with the given id in the local storage of the browser so
+/// it survives a page reload.
+///
+/// The state is restored immediately, so call this before rendering the [`TableContent`](crate::TableContent) with the
+/// same `sorting` signal and pagination controller. The saved state is dropped if it was saved with a different
+/// version or if the columns of `Row` have changed since. Without a valid saved state the initial sorting and page
+/// are kept.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_struct_table::*;
+/// # use std::collections::VecDeque;
+/// # #[derive(TableRow, Clone)]
+/// # struct Book {
+/// #     title: String,
+/// # }
+/// # fn demo() {
+/// // kept if nothing has been saved yet
+/// let sorting = RwSignal::new(VecDeque::from([(0, ColumnSort::Descending)]));
+///
+/// let UsePersistedTableStateReturn { state, .. } = use_persisted_table_state::<Book>(
+///     "books",
+///     UsePersistedTableStateOptions::default().version(1).sorting(sorting),
+/// );
+/// # }
+/// ```
+pub fn use_persisted_table_state<Row: TableRow>(
+    table_id: &str,
+    options: UsePersistedTableStateOptions,
+) -> UsePersistedTableStateReturn<impl Fn() + Clone + Send + Sync> {
+    let UsePersistedTableStateOptions {
+        version,
+        sorting,
+        pagination,
+    } = options.clone();
+
+    let (persisted, set_persisted, clear) = use_local_storage::<PersistedTableState, JsonSerdeCodec>(
+        format!("leptos-struct-table:{table_id}"),
+    );
+
+    let columns = (0..Row::column_count())
+        .map(|col_index| Row::col_name(col_index).to_string())
+        .collect::<Vec<_>>();
+
+    // nothing saved yet (the default has no columns) or saved for a different version or different columns
+    let restored_state = persisted.with_untracked(|persisted| {
+        (!columns.is_empty() && persisted.version == version && persisted.columns == columns)
+            .then(|| persisted.state.clone())
+    });
+
+    let state = RwSignal::new(options.restore::<Row>(restored_state));
+
+    if let Some(sorting) = sorting {
+        Effect::new(move || {
+            let sorting = sorting.read();
+            if state.read_untracked().sorting_for::<Row>() != *sorting {
+                state.update(|state| state.set_sorting::<Row>(&sorting));
+            }
+        });
+    }
+    if let Some(pagination) = pagination {
+        Effect::new(move || {
+            let current_page = pagination.current_page.get();
+            let page_size = pagination.page_size().get();
+
+            let changed = state.with_untracked(|state| {
+                state.current_page != current_page || state.page_size != page_size
+            });
+            if changed {
+                state.update(|state| {
+                    state.current_page = current_page;
+                    state.page_size = page_size;
+                });
+            }
+        });
+    }
+
+    Effect::watch(
+        move || state.get(),
+        move |state, _, _| {
+            set_persisted.set(PersistedTableState {
+                version,
+                columns: columns.clone(),
+                state: state.clone(),
+            });
+        },
+        false,
+    );
+
+    UsePersistedTableStateReturn { state, clear }
+}
@@ -27,6 +27,12 @@ impl CellValue<Date> for Date {
     type RenderOptions = RenderTimeOptions;
 
     fn render_value(self, options: Self::RenderOptions) -> impl IntoView {
+        self.render_text(&options)
+    }
+}
+
+impl CellText<Date> for Date {
+    fn render_text(&self, options: &Self::RenderOptions) -> String {
         if let Some(value) = options.string.as_ref() {
             let format = format_description::parse(value)
                 .expect("Unable to construct a format description given the format string");
@@ -36,6 +42,14 @@ impl CellValue<Date> for Date {
             self.to_string()
         }
     }
+
+    fn export_value(&self, _options: &Self::RenderOptions) -> ExportValue {
+        ExportValue::Date {
+            year: self.year(),
+            month: self.month().into(),
+            day: self.day(),
+        }
+    }
 }
 /// Implementation for [`Time`] to work with the [`TableRow`] derive and the [`DefaultTableCellRenderer`]
 /// ```
@@ -53,6 +67,12 @@ impl CellValue<Time> for Time {
     type RenderOptions = RenderTimeOptions;
 
     fn render_value(self, options: Self::RenderOptions) -> impl IntoView {
+        self.render_text(&options)
+    }
+}
+
+impl CellText<Time> for Time {
+    fn render_text(&self, options: &Self::RenderOptions) -> String {
         if let Some(value) = options.string.as_ref() {
             let format = format_description::parse(value)
                 .expect("Unable to construct a format description given the format string");
@@ -62,6 +82,15 @@ impl CellValue<Time> for Time {
             self.to_string()
         }
     }
+
+    fn export_value(&self, _options: &Self::RenderOptions) -> ExportValue {
+        ExportValue::Time {
+            hour: self.hour(),
+            minute: self.minute(),
+            second: self.second(),
+            nanosecond: self.nanosecond(),
+        }
+    }
 }
 
 /// Implementation for [`PrimitiveDateTime`] to work with the [`TableRow`] derive and the [`DefaultTableCellRenderer`]
@@ -80,6 +109,12 @@ impl CellValue<PrimitiveDateTime> for PrimitiveDateTime {
     type RenderOptions = RenderTimeOptions;
 
     fn render_value(self, options: Self::RenderOptions) -> impl IntoView {
+        self.render_text(&options)
+    }
+}
+
+impl CellText<PrimitiveDateTime> for PrimitiveDateTime {
+    fn render_text(&self, options: &Self::RenderOptions) -> String {
         if let Some(value) = options.string.as_ref() {
             let format = format_description::parse(value)
                 .expect("Unable to construct a format description given the format string");
@@ -89,6 +124,18 @@ impl CellValue<PrimitiveDateTime> for PrimitiveDateTime {
             self.to_string()
         }
     }
+
+    fn export_value(&self, _options: &Self::RenderOptions) -> ExportValue {
+        ExportValue::DateTime {
+            year: self.year(),
+            month: self.month().into(),
+            day: self.day(),
+            hour: self.hour(),
+            minute: self.minute(),
+            second: self.second(),
+            nanosecond: self.nanosecond(),
+        }
+    }
 }
 
 /// Implementation for [`OffsetDateTime`] to work with the [`TableRow`] derive and the [`DefaultTableCellRenderer`]
@@ -107,6 +154,12 @@ impl CellValue<OffsetDateTime> for OffsetDateTime {
     type RenderOptions = RenderTimeOptions;
 
     fn render_value(self, options: Self::RenderOptions) -> impl IntoView {
+        self.render_text(&options)
+    }
+}
+
+impl CellText<OffsetDateTime> for OffsetDateTime {
+    fn render_text(&self, options: &Self::RenderOptions) -> String {
         if let Some(value) = options.string.as_ref() {
             let format = format_description::parse(value)
                 .expect("Unable to construct a format description given the format string");
@@ -116,4 +169,17 @@ impl CellValue<OffsetDateTime> for OffsetDateTime {
             self.to_string()
         }
     }
+
+    /// Excel has no time zones so the date and time are exported in the value's offset.
+    fn export_value(&self, _options: &Self::RenderOptions) -> ExportValue {
+        ExportValue::DateTime {
+            year: self.year(),
+            month: self.month().into(),
+            day: self.day(),
+            hour: self.hour(),
+            minute: self.minute(),
+            second: self.second(),
+            nanosecond: self.nanosecond(),
+        }
+    }
 }
@@ -0,0 +1,263 @@
+use crate::{sorting_by_name, ColumnSort, PaginationController, TableRow};
+use leptos::prelude::*;
+use leptos_router::hooks::{use_location, use_navigate};
+use leptos_router::location::Location;
+use leptos_router::params::ParamsMap;
+use leptos_router::NavigateOptions;
+use std::borrow::Cow;
+use std::collections::VecDeque;
+
+/// Options for [`use_table_url_sync`].
+#[derive(Clone)]
+pub struct UseTableUrlSyncOptions {
+    sort_param: Cow<'static, str>,
+    page_param: Cow<'static, str>,
+    pagination: Option<PaginationController>,
+    filters: Vec<(Cow<'static, str>, RwSignal<String>)>,
+    replace: bool,
+}
+
+impl Default for UseTableUrlSyncOptions {
+    fn default() -> Self {
+        Self {
+            sort_param: "sort".into(),
+            page_param: "page".into(),
+            pagination: None,
+            filters: vec![],
+            replace: false,
+        }
+    }
+}
+
+impl UseTableUrlSyncOptions {
+    /// The name of the query parameter of the sorting. Defaults to `"sort"`.
+    pub fn sort_param(mut self, sort_param: impl Into<Cow<'static, str>>) -> Self {
+        self.sort_param = sort_param.into();
+        self
+    }
+
+    /// The name of the query parameter of the current page. Defaults to `"page"`.
+    pub fn page_param(mut self, page_param: impl Into<Cow<'static, str>>) -> Self {
+        self.page_param = page_param.into();
+        self
+    }
+
+    /// Syncs the current page of the controller. Pages are counted from `1` in the URL.
+    pub fn pagination(mut self, controller: PaginationController) -> Self {
+        self.pagination = Some(controller);
+        self
+    }
+
+    /// Syncs the filter value with the query parameter of the given name. Empty values are left out of the URL.
+    pub fn filter(mut self, param: impl Into<Cow<'static, str>>, value: RwSignal<String>) -> Self {
+        self.filters.push((param.into(), value));
+        self
+    }
+
+    /// If `true` changes replace the current history entry instead of adding one. Defaults to `false`
+    /// which lets the back button restore the previous state.
+    pub fn replace(mut self, replace: bool) -> Self {
+        self.replace = replace;
+        self
+    }
+}
+
+/// Syncs the sorting, the current page and filters of a table with the query parameters of the URL.
+///
+/// The state is restored from the URL immediately, so call this before rendering the [`TableContent`](crate::TableContent)
+/// with the same `sorting` signal. This way the table loads the restored state right away without an extra reload.
+/// Going back and forth in the browser history restores the state of the table as well.
+///
+/// The sorting is stored by column name like `?sort=title,-published_at` where `-` stands for descending.
+/// If the URL has no sort parameter the sorting the signal starts with is kept. That sorting is never written to
+/// the URL, so loading the page doesn't add a history entry.
+/// Requires the feature `leptos_router` and has to be called inside of a `<Router>`.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_struct_table::*;
+/// # #[derive(TableRow, Clone)]
+/// # struct Book {
+/// #     title: String,
+/// # }
+/// # fn demo() {
+/// // kept if the URL has no sort parameter
+/// let sorting = RwSignal::new(sorting_by_name::<Book>([("title", ColumnSort::Ascending)]));
+/// let controller = PaginationController::default();
+/// let search = RwSignal::new(String::new());
+///
+/// use_table_url_sync::<Book>(
+///     sorting,
+///     UseTableUrlSyncOptions::default()
+///         .pagination(controller)
+///         .filter("q", search),
+/// );
+/// # }
+/// ```
+pub fn use_table_url_sync<Row: TableRow>(
+    sorting: RwSignal<VecDeque<(usize, ColumnSort)>>,
+    options: UseTableUrlSyncOptions,
+) {
+    let Location {
+        pathname,
+        query,
+        hash,
+        ..
+    } = use_location();
+    let navigate = use_navigate();
+
+    let UseTableUrlSyncOptions {
+        sort_param,
+        page_param,
+        pagination,
+        filters,
+        replace,
+    } = options;
+    // a URL without the sort parameter stands for the sorting the table starts with
+    let initial_sorting = sorting.get_untracked();
+    let options = StoredValue::new((sort_param, page_param, pagination, filters, initial_sorting));
+
+    let restore = move |query: &ParamsMap| {
+        options.with_value(
+            |(sort_param, page_param, pagination, filters, initial_sorting)| {
+                let restored_sorting = match query.get_str(sort_param) {
+                    Some(value) => sorting_from_query_value::<Row>(value),
+                    None => initial_sorting.clone(),
+                };
+                if *sorting.read_untracked() != restored_sorting {
+                    sorting.set(restored_sorting);
+                }
+
+                if let Some(pagination) = pagination {
+                    let page = query
+                        .get_str(page_param)
+                        .and_then(|page| page.parse::<usize>().ok())
+                        .unwrap_or(1)
+                        .saturating_sub(1);
+                    if pagination.current_page.get_untracked() != page {
+                        pagination.current_page.set(page);
+                    }
+                }
+
+                for (param, value) in filters {
+                    let restored_value = query.get(param).unwrap_or_default();
+                    if *value.read_untracked() != restored_value {
+                        value.set(restored_value);
+                    }
+                }
+            },
+        );
+    };
+
+    // restore before the table is rendered so it loads the right data right away
+    query.with_untracked(restore);
+
+    // back and forward navigation
+    Effect::watch(
+        move || query.get(),
+        move |query, _, _| restore(query),
+        false,
+    );
+
+    Effect::new(move |prev: Option<()>| {
+        let mut new_query = query.get_untracked();
+
+        options.with_value(
+            |(sort_param, page_param, pagination, filters, initial_sorting)| {
+                let sort_value = sorting_to_query_value::<Row>(&sorting.read());
+                if sort_value == sorting_to_query_value::<Row>(initial_sorting) {
+                    // restored without the parameter
+                    new_query.remove(sort_param);
+                } else {
+                    // an empty parameter keeps a cleared sorting from being restored as the initial one
+                    new_query.replace(sort_param.to_string(), sort_value);
+                }
+
+                if let Some(pagination) = pagination {
+                    let page = pagination.current_page.get();
+                    set_query_param(
+                        &mut new_query,
+                        page_param,
+                        if page == 0 {
+                            String::new()
+                        } else {
+                            (page + 1).to_string()
+                        },
+                    );
+                }
+
+                for (param, value) in filters {
+                    set_query_param(&mut new_query, param, value.get());
+                }
+            },
+        );
+
+        let new_query = new_query.to_query_string();
+        if new_query != query.get_untracked().to_query_string() {
+            navigate(
+                &format!(
+                    "{}{}{}",
+                    pathname.get_untracked(),
+                    new_query,
+                    hash.get_untracked()
+                ),
+                NavigateOptions {
+                    resolve: false,
+                    // normalizing the URL on load isn't a new state
+                    replace: replace || prev.is_none(),
+                    scroll: false,
+                    ..Default::default()
+                },
+            );
+        }
+    });
+}
+
+fn set_query_param(query: &mut ParamsMap, param: &str, value: String) {
+    if value.is_empty() {
+        query.remove(param);
+    } else {
+        query.replace(param.to_string(), value);
+    }
+}
+
+/// Serializes the sorting into the value of a query parameter by column name.
+/// Descending columns are prefixed with `-`.
+///
+/// ```
+/// # use leptos_struct_table::*;
+/// # use std::collections::VecDeque;
+/// # #[derive(TableRow, Clone)]
+/// # struct Book {
+/// #     title: String,
+/// #     published_at: u32,
+/// # }
+/// let sorting = VecDeque::from([(1, ColumnSort::Descending), (0, ColumnSort::Ascending)]);
+///
+/// assert_eq!(sorting_to_query_value::<Book>(&sorting), "-published_at,title");
+/// assert_eq!(sorting_from_query_value::<Book>("-published_at,title"), sorting);
+/// ```
+pub fn sorting_to_query_value<Row: TableRow>(sorting: &VecDeque<(usize, ColumnSort)>) -> String {
+    sorting
+        .iter()
+        .filter_map(|(col_index, sort)| match sort {
+            ColumnSort::Ascending => Some(Row::col_name(*col_index).to_string()),
+            ColumnSort::Descending => Some(format!("-{}", Row::col_name(*col_index))),
+            ColumnSort::None => None,
+        })
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// Parses the sorting from the value of a query parameter created by [`sorting_to_query_value`].
+/// Unknown column names are ignored.
+pub fn sorting_from_query_value<Row: TableRow>(value: &str) -> VecDeque<(usize, ColumnSort)> {
+    sorting_by_name::<Row>(
+        value
+            .split(',')
+            .map(|part| match part.trim().strip_prefix('-') {
+                Some(col_name) => (col_name, ColumnSort::Descending),
+                None => (part.trim(), ColumnSort::Ascending),
+            }),
+    )
+}
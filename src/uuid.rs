@@ -21,3 +21,11 @@ impl CellValue<Uuid> for Uuid {
         self.to_string()
     }
 }
+
+impl CellText<Uuid> for Uuid {
+    fn render_text(&self, _options: &Self::RenderOptions) -> String {
+        self.to_string()
+    }
+}
+
+parse_cell_value_from_str![Uuid];